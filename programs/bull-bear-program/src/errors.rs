@@ -30,5 +30,152 @@ pub enum BullBearProgramError {
     WrongTokenAddress,
     #[msg("The provided mint account does not match the expected token address.")]
     InvalidMintAccount,
+    #[msg("Price account does not match the feed id.")]
+    FeedIdMismatch,
+    #[msg("Invalid price.")]
+    InvalidPrice,
+    #[msg("Round is in progress.")]
+    RoundInProgress,
+    #[msg("Invalid legacy game account.")]
+    InvalidLegacyGame,
+    #[msg("TWAP settlement is not enabled.")]
+    TwapNotEnabled,
+    #[msg("TWAP window exceeds half the round interval.")]
+    InvalidTwapWindow,
+    #[msg("Price sampling window is closed.")]
+    SamplingWindowClosed,
+    #[msg("Price sample already recorded.")]
+    PriceSampleAlreadyRecorded,
+    #[msg("Price deviates too far from EMA.")]
+    PriceDeviatesFromEma,
+    #[msg("EMA deviation needs to be positive.")]
+    InvalidEmaDeviation,
+    #[msg("Flat band needs to be below 100%.")]
+    InvalidFlatBand,
+    #[msg("Invalid price buckets.")]
+    InvalidBuckets,
+    #[msg("Account is not in the legacy layout.")]
+    InvalidLegacyAccount,
+    #[msg("Second price feed is missing.")]
+    MissingSecondFeed,
+    #[msg("TWAP settlement is not supported for head-to-head games.")]
+    TwapNotSupported,
+    #[msg("Invalid odds.")]
+    InvalidOdds,
+    #[msg("No odds quoted for this outcome.")]
+    OddsNotQuoted,
+    #[msg("Exposure limit needs to be between 0 and 100%.")]
+    InvalidExposureLimit,
+    #[msg("House exposure limit exceeded.")]
+    ExposureLimitExceeded,
+    #[msg("Liquidity pool already initialized.")]
+    LiquidityPoolInitialized,
+    #[msg("Liquidity pool not initialized.")]
+    LiquidityPoolNotInitialized,
+    #[msg("House funds belong to the liquidity pool.")]
+    LiquidityPoolEnabled,
+    #[msg("Invalid liquidity amount.")]
+    InvalidLiquidityAmount,
+    #[msg("Insufficient house funds.")]
+    InsufficientHouseFunds,
+    #[msg("Cancel penalty needs to be below 100%.")]
+    InvalidCancelPenalty,
+    #[msg("Switch fee needs to be below 100%.")]
+    InvalidSwitchFee,
+    #[msg("Bet is tokenized.")]
+    BetIsTokenized,
+    #[msg("Bet receipt is missing.")]
+    MissingReceipt,
+    #[msg("Bet receipt is not held by the signer.")]
+    ReceiptNotHeld,
+    #[msg("Early exit is not enabled.")]
+    EarlyExitNotEnabled,
+    #[msg("Early exit is not supported for this outcome.")]
+    EarlyExitNotSupported,
+    #[msg("Exit spread needs to be below 100%.")]
+    InvalidExitSpread,
+    #[msg("Bet has been exited.")]
+    BetExited,
+    #[msg("Auto-bet needs a positive amount and number of rounds.")]
+    InvalidAutoBet,
+    #[msg("Auto-bet has no rounds remaining.")]
+    AutoBetExhausted,
+    #[msg("Bet amount needs to be positive.")]
+    InvalidBetAmount,
+    #[msg("Bet intent is not signed by the player.")]
+    InvalidSignature,
+    #[msg("Bet intent does not match the accounts.")]
+    IntentMismatch,
+    #[msg("Bet intent has expired.")]
+    IntentExpired,
+    #[msg("Invalid bet intent nonce.")]
+    InvalidNonce,
+    #[msg("Session needs a future expiry and a positive spend limit.")]
+    InvalidSession,
+    #[msg("Session has expired.")]
+    SessionExpired,
+    #[msg("Session spend limit exceeded.")]
+    SessionLimitExceeded,
+    #[msg("Token account delegates an allowance to another account.")]
+    DelegateInUse,
+    #[msg("Player is not on the game allowlist.")]
+    NotAllowlisted,
+    #[msg("Game requires committed bets.")]
+    CommitRevealRequired,
+    #[msg("Commit-reveal betting is not enabled.")]
+    CommitRevealNotEnabled,
+    #[msg("Commit-reveal betting does not support fixed odds.")]
+    CommitRevealNotSupported,
+    #[msg("Reveal window needs to be positive and fit into the locked phase.")]
+    InvalidRevealWindow,
+    #[msg("Reveal phase is not open.")]
+    RevealPhaseNotOpen,
+    #[msg("Reveal phase has not ended.")]
+    RevealPhaseNotEnded,
+    #[msg("Reveal does not match the commitment.")]
+    InvalidReveal,
+    #[msg("Bet has not been revealed.")]
+    BetNotRevealed,
+    #[msg("Bet has already been revealed.")]
+    BetAlreadyRevealed,
+    #[msg("Bet did not lose.")]
+    BetNotLost,
+    #[msg("Pending bet of the player stats needs to be settled first.")]
+    PendingBetNotSettled,
+    #[msg("Settlement fee needs to be below 100%.")]
+    InvalidSettlementFee,
+    #[msg("Season needs a future end and payouts for up to ten places summing to at most 100%.")]
+    InvalidSeason,
+    #[msg("Game already has a running season.")]
+    SeasonActive,
+    #[msg("Season has ended.")]
+    SeasonEnded,
+    #[msg("Season has not ended.")]
+    SeasonNotEnded,
+    #[msg("Ranking does not match the season entries.")]
+    InvalidRanking,
+    #[msg("Season ranking is final.")]
+    RankingFinal,
+    #[msg("Season ranking is not final.")]
+    RankingNotFinal,
+    #[msg("Entry does not outrank the committed ranking.")]
+    RankingNotChallenged,
+    #[msg("Player is not ranked in the season.")]
+    NotRanked,
+    #[msg("Season prize already claimed.")]
+    SeasonPrizeClaimed,
+    #[msg("Players cannot refer themselves.")]
+    InvalidReferrer,
+    #[msg("Referral share needs to be at most 100%.")]
+    InvalidReferralShare,
+    #[msg("Referral rewards account of the referrer is missing.")]
+    MissingReferralRewards,
+    #[msg("Loyalty points are already initialized.")]
+    PointsInitialized,
+    #[msg("Loyalty points are not initialized.")]
+    PointsNotInitialized,
+    #[msg("Points rate needs to be positive.")]
+    InvalidPointsRate,
+    #[msg("Points account of the bet owner is missing.")]
+    MissingPointsAccount,
 }
-
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+
+pub fn bind_referrer(ctx: Context<BindReferrerContext>, referrer: Pubkey) -> Result<()> {
+
+    let referral = &mut ctx.accounts.referral;
+
+    // check that the player does not refer themselves
+    require!(referrer != *ctx.accounts.player.key && referrer != Pubkey::default(), BullBearProgramError::InvalidReferrer);
+
+    // the referral account exists once per player, so the referrer cannot be changed
+    referral.player = ctx.accounts.player.key();
+    referral.referrer = referrer;
+    referral.bump = ctx.bumps.referral;
+
+    msg!("Referrer bound: {}", referrer);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct BindReferrerContext<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        init,
+        payer = player,
+        space = 8 + Referral::INIT_SPACE,
+        seeds = [
+            REFERRAL_SEED.as_bytes(),
+            player.key().as_ref()
+            ],
+        bump)]
+    pub referral: Account<'info, Referral>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
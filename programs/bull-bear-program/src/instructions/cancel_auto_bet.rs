@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{revoke, spl_token, Mint, Revoke, Token, TokenAccount};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::is_delegate;
+
+pub fn cancel_auto_bet(ctx: Context<CancelAutoBetContext>) -> Result<()> {
+
+    let auto_bet = &ctx.accounts.auto_bet;
+
+    // check if authorized
+    require!(auto_bet.player == *ctx.accounts.player.key, BullBearProgramError::SignerNotAuthorized);
+
+    // remove the remaining allowance of the auto-bet (the token account may delegate to another account by now)
+    if is_delegate(&ctx.accounts.signer_vault, &auto_bet.key()) {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Revoke{source: ctx.accounts.signer_vault.to_account_info(), authority: ctx.accounts.player.to_account_info()}
+        );
+        revoke(cpi_context)?;
+    }
+
+    msg!("Auto-bet cancelled with {} rounds remaining", auto_bet.remaining);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct CancelAutoBetContext<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        close = player,
+        seeds = [
+            AUTO_BET_SEED.as_bytes(),
+            player.key().as_ref(),
+            game.key().as_ref()
+            ],
+        bump = auto_bet.bump)]
+    pub auto_bet: Account<'info, AutoBet>,
+     #[account(
+        constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = player,
+    )]
+    pub signer_vault: Account<'info, TokenAccount>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{spl_token, transfer, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::{record_season_return, undo_player_wager};
+
+pub fn cancel_bet(ctx: Context<CancelBetContext>) -> Result<()> {
+
+    let game = &mut ctx.accounts.game;
+    let round = &mut ctx.accounts.round;
+    let bet = &ctx.accounts.bet;
+
+    // check if authorized
+    require!(bet.player == *ctx.accounts.player.key, BullBearProgramError::SignerNotAuthorized);
+    // check that the position was not handed out as a receipt
+    require!(!bet.is_tokenized(), BullBearProgramError::BetIsTokenized);
+    // check if round active
+    require!(round.status == RoundStatus::Active, BullBearProgramError::RoundNotActive);
+    // check if betting open
+    require!(round.betting == BettingStatus::Open, BullBearProgramError::BettingIsClosed);
+
+    // remove stake from the pool (committed bets have no side yet)
+    if bet.outcome == NO_OUTCOME {
+        round.committed -= bet.amount;
+    }
+    else {
+        round.totals[bet.outcome as usize] -= bet.amount;
+    }
+    round.num_bets -= 1;
+
+    // the wager is removed from the player stats
+    undo_player_wager(&ctx.accounts.player_stats, bet.key(), bet.amount)?;
+
+    // fixed-odds bets release their payout from the house exposure
+    if bet.odds_bps > 0 {
+        round.payouts[bet.outcome as usize] -= bet.fixed_payout();
+        game.exposure = round.house_exposure();
+    }
+
+    // penalty goes to the game vault, the rest is refunded
+    let penalty = (bet.amount as u128 * game.cancel_penalty_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+    let refund = bet.amount - penalty;
+
+    // the refunded stake no longer counts against the season
+    record_season_return(ctx.accounts.season.as_ref(), &ctx.accounts.season_entry, bet, refund)?;
+
+    let game_id = game.key();
+    let round_ref = round.round_nr.to_le_bytes();
+    let bump = round.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[ROUND_SEED.as_bytes(),
+            game_id.as_ref(),
+            round_ref.as_ref(), &[bump]]];
+
+    if penalty > 0 {
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer{from: ctx.accounts.vault.to_account_info(), to: ctx.accounts.game_vault.to_account_info(), authority: round.to_account_info()},
+            signer_seeds
+        );
+        transfer(cpi_context, penalty)?;
+    }
+
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer{from: ctx.accounts.vault.to_account_info(), to: ctx.accounts.signer_vault.to_account_info(), authority: round.to_account_info()},
+        signer_seeds
+    );
+    transfer(cpi_context, refund)?;
+
+    msg!("Bet cancelled: {} (penalty {})", refund, penalty);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct CancelBetContext<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED.as_bytes(),
+            game.key().as_ref(),
+            game.counter.to_le_bytes().as_ref(),
+        ],
+        bump = round.bump
+    )]
+    pub round: Account<'info, Round>,
+    // the bet rent always goes back to the player owning the bet, whoever paid it
+    #[account(
+        mut,
+        close = player,
+        seeds = [
+            BET_SEED.as_bytes(),
+            player.key().as_ref(),
+            round.key().as_ref()
+            ],
+        bump = bet.bump)]
+    pub bet: Account<'info, Bet>,
+    /// CHECK: stats of the player, updated once opened
+    #[account(
+        mut,
+        seeds = [
+            PLAYER_STATS_SEED.as_bytes(),
+            player.key().as_ref(),
+            game.key().as_ref()
+            ],
+        bump)]
+    pub player_stats: UncheckedAccount<'info>,
+    /// CHECK: season entry the stake of the bet counted against, updated once joined
+    #[account(
+        mut,
+        seeds = [
+            SEASON_ENTRY_SEED.as_bytes(),
+            bet.season.as_ref(),
+            player.key().as_ref()
+            ],
+        bump)]
+    pub season_entry: UncheckedAccount<'info>,
+    #[account(address = bet.season)]
+    pub season: Option<Account<'info, Season>>,
+     #[account(
+        constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = round,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = game,
+    )]
+    pub game_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = player,
+    )]
+    pub signer_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = spl_associated_token_account::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
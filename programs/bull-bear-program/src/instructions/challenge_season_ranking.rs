@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+
+pub fn challenge_season_ranking(ctx: Context<ChallengeSeasonRankingContext>) -> Result<()> {
+
+    let season = &mut ctx.accounts.season;
+    let season_entry = &ctx.accounts.season_entry;
+
+    // check that a ranking was committed and is still open to challenges
+    let now = Clock::get()?.unix_timestamp;
+    require!(season.ranked_at > 0, BullBearProgramError::RankingNotFinal);
+    require!(!season.is_final(now), BullBearProgramError::RankingFinal);
+
+    // check that an unranked entry beats the last paid place
+    require!(season.rank_of(&season_entry.player).is_none()
+        && season_entry.net_winnings > season.cutoff, BullBearProgramError::RankingNotChallenged);
+
+    // the authority needs to commit a new ranking
+    season.ranking = [Pubkey::default(); MAX_SEASON_WINNERS];
+    season.num_ranked = 0;
+    season.ranked_at = 0;
+    season.cutoff = 0;
+    season.prize_total = 0;
+
+    msg!("Season ranking rejected.");
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct ChallengeSeasonRankingContext<'info> {
+    pub challenger: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            SEASON_SEED.as_bytes(),
+            season.game.as_ref(),
+            season.season_nr.to_le_bytes().as_ref(),
+            ],
+        bump = season.bump)]
+    pub season: Account<'info, Season>,
+    #[account(
+        seeds = [
+            SEASON_ENTRY_SEED.as_bytes(),
+            season.key().as_ref(),
+            season_entry.player.as_ref()
+            ],
+        bump = season_entry.bump)]
+    pub season_entry: Account<'info, SeasonEntry>,
+}
@@ -1,11 +1,12 @@
 
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
-use anchor_spl::token::{spl_token, transfer, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token::{burn, spl_token, transfer, Burn, Mint, Token, TokenAccount, Transfer};
 use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
 
 use crate::errors::BullBearProgramError;
 use crate::states::*;
+use crate::utils::{accrue_referral_reward, claim_bet, mint_bet_points, record_season_return, settle_player_bet};
 
 pub fn claim_prize(ctx: Context<ClaimPrizeContext>) -> Result<()> {
 
@@ -13,28 +14,32 @@ pub fn claim_prize(ctx: Context<ClaimPrizeContext>) -> Result<()> {
     let round = &mut ctx.accounts.round;
     let bet = &mut ctx.accounts.bet; 
 
-    // check if authorized
-    require!(bet.player == *ctx.accounts.player.key, BullBearProgramError::SignerNotAuthorized);
-    // check if round ended
-    require!(round.status == RoundStatus::Ended, BullBearProgramError::CurrentRoundNotEnded);
-    // check if claimable
-    require!(round.result == bet.prediction, BullBearProgramError::NoPrizeClaimable);
-    // check if already claimed
-    require!(bet.claimed == false, BullBearProgramError::PrizeAlreadyClaimed);
-
-    // calculate prize
-    let prize_pool = round.total_up + round.total_down;
-    let mut prize = 0;
-    
-    if round.result == PriceMovement::Bull && round.total_up > 0 {
-        prize = bet.amount  / round.total_up * prize_pool
+    // check if authorized (tokenized bets pay whoever holds the receipt)
+    if bet.is_tokenized() {
+        let receipt_mint = ctx.accounts.receipt_mint.as_ref().ok_or(BullBearProgramError::MissingReceipt)?;
+        let receipt_account = ctx.accounts.receipt_account.as_ref().ok_or(BullBearProgramError::MissingReceipt)?;
+        require!(receipt_mint.key() == bet.receipt_mint, BullBearProgramError::MissingReceipt);
+        require!(receipt_account.mint == bet.receipt_mint
+            && receipt_account.owner == *ctx.accounts.player.key
+            && receipt_account.amount == 1, BullBearProgramError::ReceiptNotHeld);
     }
-    else if round.result == PriceMovement::Bear && round.total_down > 0 {
-        prize = bet.amount  / round.total_down * prize_pool ;
+    else {
+        require!(bet.player == *ctx.accounts.player.key, BullBearProgramError::SignerNotAuthorized);
     }
+    let prize = claim_bet(round, bet)?;
+    accrue_referral_reward(game, round, bet, &ctx.accounts.referral_rewards)?;
+
+    // the win is recorded while it is the pending bet of the player stats (refunds do not count)
+    settle_player_bet(&ctx.accounts.player_stats, bet.key(), round, bet)?;
+
+    // loyalty points of the stake are minted once the bet settled
+    mint_bet_points(game, round, bet, ctx.accounts.points_mint.as_ref(), ctx.accounts.points_account.as_ref(), &ctx.accounts.token_program)?;
+
+    // prizes of bets staked during a season count towards its net winnings
+    record_season_return(ctx.accounts.season.as_ref(), &ctx.accounts.season_entry, bet, prize)?;
     
     // transfer prize
-    let game_id = game.key();
+    let game_id = round.game;
     let round_ref = round.round_nr.to_le_bytes();
     let bump = round.bump;
     let signer_seeds: &[&[&[u8]]] = &[&[ROUND_SEED.as_bytes(),
@@ -48,7 +53,14 @@ pub fn claim_prize(ctx: Context<ClaimPrizeContext>) -> Result<()> {
     
     transfer(cpi_context, prize)?;
 
-    bet.claimed = true;
+    // burn receipt
+    if let (true, Some(receipt_mint), Some(receipt_account)) = (bet.is_tokenized(), &ctx.accounts.receipt_mint, &ctx.accounts.receipt_account) {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn{mint: receipt_mint.to_account_info(), from: receipt_account.to_account_info(), authority: ctx.accounts.player.to_account_info()}
+        );
+        burn(cpi_context, 1)?;
+    }
 
     msg!("Prize claimed: {}", prize);
     Ok(())
@@ -60,21 +72,23 @@ pub struct ClaimPrizeContext<'info> {
     #[account(mut)]
     pub player: Signer<'info>,
     #[account(
+        mut,
         seeds = [
             GAME_SEED.as_bytes(),
             game.game_authority.as_ref(),
             game.protocol.as_ref(),
             game.token.as_ref(),
-            game.feed_account.as_ref()
+            game.feed_id.as_ref()
         ],
         bump = game.bump
     )]
     pub game: Account<'info, Game>,
     #[account(
         mut,
+        constraint = round.game == game.key() || round.game == game.legacy_game @ BullBearProgramError::InvalidLegacyGame,
         seeds = [
             ROUND_SEED.as_bytes(),
-            game.key().as_ref(),
+            round.game.as_ref(),
             round.round_nr.to_le_bytes().as_ref(),
         ],
         bump = round.bump
@@ -84,15 +98,62 @@ pub struct ClaimPrizeContext<'info> {
         mut,
         seeds = [
             BET_SEED.as_bytes(),
-            player.key().as_ref(),
+            bet.player.as_ref(),
             round.key().as_ref()
             ],
         bump = bet.bump)]
     pub bet: Account<'info, Bet>,
+    /// CHECK: stats of the bet owner, updated once opened
+    #[account(
+        mut,
+        seeds = [
+            PLAYER_STATS_SEED.as_bytes(),
+            bet.player.as_ref(),
+            game.key().as_ref()
+            ],
+        bump)]
+    pub player_stats: UncheckedAccount<'info>,
+    /// CHECK: season entry the stake of the bet counted against, updated once joined
+    #[account(
+        mut,
+        seeds = [
+            SEASON_ENTRY_SEED.as_bytes(),
+            bet.season.as_ref(),
+            bet.player.as_ref()
+            ],
+        bump)]
+    pub season_entry: UncheckedAccount<'info>,
+    #[account(address = bet.season)]
+    pub season: Option<Account<'info, Season>>,
+    /// CHECK: rewards of the referrer of the bet, updated once opened
+    #[account(
+        mut,
+        seeds = [
+            REFERRAL_REWARDS_SEED.as_bytes(),
+            bet.referrer.as_ref(),
+            game.key().as_ref()
+            ],
+        bump)]
+    pub referral_rewards: UncheckedAccount<'info>,
      #[account(
         constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
     )]
     pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        address = game.points_mint
+    )]
+    pub points_mint: Option<Account<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = points_mint,
+        constraint = points_account.owner == bet.player @ BullBearProgramError::MissingPointsAccount
+    )]
+    pub points_account: Option<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub receipt_mint: Option<Account<'info, Mint>>,
+    #[account(mut)]
+    pub receipt_account: Option<Account<'info, TokenAccount>>,
     #[account(
         mut,
         associated_token::mint = mint,
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{spl_token, transfer, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+
+pub fn claim_referrer_rewards(ctx: Context<ClaimReferrerRewardsContext>) -> Result<()> {
+
+    let game = &mut ctx.accounts.game;
+    let referral_rewards = &mut ctx.accounts.referral_rewards;
+    let amount = referral_rewards.accrued;
+
+    // check if rewards accrued
+    require!(amount > 0, BullBearProgramError::NothingToWithdraw);
+
+    referral_rewards.accrued = 0;
+    referral_rewards.claimed += amount;
+    game.referral_liability -= amount;
+
+    // check that the house can pay them (the fees were paid into the game vault)
+    require!(amount <= game.house_funds(ctx.accounts.game_vault.amount), BullBearProgramError::InsufficientHouseFunds);
+
+    // transfer rewards from the game vault
+    let game_authority = game.game_authority;
+    let game_protocol = game.protocol;
+    let game_token = game.token;
+    let game_feed = game.feed_id;
+    let bump = game.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[GAME_SEED.as_bytes(),
+            game_authority.as_ref(),
+            game_protocol.as_ref(),
+            game_token.as_ref(),
+            game_feed.as_ref(),
+            &[bump]]];
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer{from: ctx.accounts.game_vault.to_account_info(), to: ctx.accounts.signer_vault.to_account_info(), authority: game.to_account_info()},
+        signer_seeds
+    );
+    transfer(cpi_context, amount)?;
+
+    msg!("Referral rewards claimed: {}", amount);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct ClaimReferrerRewardsContext<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [
+            REFERRAL_REWARDS_SEED.as_bytes(),
+            referrer.key().as_ref(),
+            game.key().as_ref()
+            ],
+        bump = referral_rewards.bump)]
+    pub referral_rewards: Account<'info, ReferralRewards>,
+     #[account(
+        constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = game,
+    )]
+    pub game_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = referrer,
+    )]
+    pub signer_vault: Account<'info, TokenAccount>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = spl_associated_token_account::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
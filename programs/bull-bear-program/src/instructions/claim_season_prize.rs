@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{spl_token, transfer, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+
+pub fn claim_season_prize(ctx: Context<ClaimSeasonPrizeContext>) -> Result<()> {
+
+    let season = &ctx.accounts.season;
+    let season_entry = &mut ctx.accounts.season_entry;
+
+    // check that the ranking survived the challenge period
+    require!(season.is_final(Clock::get()?.unix_timestamp), BullBearProgramError::RankingNotFinal);
+
+    // check that the player holds a paid place
+    let rank = season.rank_of(ctx.accounts.player.key).ok_or(BullBearProgramError::NotRanked)?;
+
+    // check if already claimed
+    require!(!season_entry.claimed, BullBearProgramError::SeasonPrizeClaimed);
+
+    let prize = (season.prize_total as u128 * season.payout_bps[rank] as u128 / BPS_DENOMINATOR as u128) as u64;
+    season_entry.claimed = true;
+
+    // transfer prize
+    let game_id = season.game;
+    let season_ref = season.season_nr.to_le_bytes();
+    let bump = season.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[SEASON_SEED.as_bytes(),
+            game_id.as_ref(),
+            season_ref.as_ref(), &[bump]]];
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer{from: ctx.accounts.season_vault.to_account_info(), to: ctx.accounts.signer_vault.to_account_info(), authority: season.to_account_info()},
+        signer_seeds
+    );
+
+    transfer(cpi_context, prize)?;
+
+    msg!("Season prize claimed: {}", prize);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct ClaimSeasonPrizeContext<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        seeds = [
+            SEASON_SEED.as_bytes(),
+            game.key().as_ref(),
+            season.season_nr.to_le_bytes().as_ref(),
+            ],
+        bump = season.bump)]
+    pub season: Account<'info, Season>,
+    #[account(
+        mut,
+        seeds = [
+            SEASON_ENTRY_SEED.as_bytes(),
+            season.key().as_ref(),
+            player.key().as_ref()
+            ],
+        bump = season_entry.bump)]
+    pub season_entry: Account<'info, SeasonEntry>,
+     #[account(
+        constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = season,
+    )]
+    pub season_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = player,
+    )]
+    pub signer_vault: Account<'info, TokenAccount>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = spl_associated_token_account::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
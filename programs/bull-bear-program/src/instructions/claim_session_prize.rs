@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{spl_token, transfer, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::{accrue_referral_reward, claim_bet, mint_bet_points, record_season_return, settle_player_bet};
+
+pub fn claim_session_prize(ctx: Context<ClaimSessionPrizeContext>) -> Result<()> {
+
+    let game = &mut ctx.accounts.game;
+    let round = &mut ctx.accounts.round;
+    let session = &ctx.accounts.session;
+    let bet = &mut ctx.accounts.bet;
+
+    // check if session active
+    require!(Clock::get()?.unix_timestamp < session.expiry, BullBearProgramError::SessionExpired);
+    // check if bet belongs to the main wallet
+    require!(bet.player == session.player, BullBearProgramError::SignerNotAuthorized);
+    // receipts are claimed by their holder
+    require!(!bet.is_tokenized(), BullBearProgramError::BetIsTokenized);
+
+    let prize = claim_bet(round, bet)?;
+    accrue_referral_reward(game, round, bet, &ctx.accounts.referral_rewards)?;
+
+    // the win is recorded while it is the pending bet of the player stats (refunds do not count)
+    settle_player_bet(&ctx.accounts.player_stats, bet.key(), round, bet)?;
+
+    // loyalty points of the stake are minted once the bet settled
+    mint_bet_points(game, round, bet, ctx.accounts.points_mint.as_ref(), ctx.accounts.points_account.as_ref(), &ctx.accounts.token_program)?;
+
+    // prizes of bets staked during a season count towards its net winnings
+    record_season_return(ctx.accounts.season.as_ref(), &ctx.accounts.season_entry, bet, prize)?;
+
+    // transfer prize to the main wallet
+    let game_id = game.key();
+    let round_ref = round.round_nr.to_le_bytes();
+    let bump = round.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[ROUND_SEED.as_bytes(),
+            game_id.as_ref(),
+            round_ref.as_ref(), &[bump]]];
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer{from: ctx.accounts.vault.to_account_info(), to: ctx.accounts.player_vault.to_account_info(), authority: round.to_account_info()},
+        signer_seeds
+    );
+    transfer(cpi_context, prize)?;
+
+    msg!("Prize claimed: {}", prize);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct ClaimSessionPrizeContext<'info> {
+    #[account(mut)]
+    pub session_key: Signer<'info>,
+    /// CHECK: main wallet of the session, checked against the session account
+    #[account(address = session.player)]
+    pub player: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+            SESSION_SEED.as_bytes(),
+            session.player.as_ref(),
+            session_key.key().as_ref()
+            ],
+        bump = session.bump)]
+    pub session: Account<'info, Session>,
+    #[account(
+        mut,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED.as_bytes(),
+            game.key().as_ref(),
+            round.round_nr.to_le_bytes().as_ref(),
+        ],
+        bump = round.bump
+    )]
+    pub round: Account<'info, Round>,
+    #[account(
+        mut,
+        seeds = [
+            BET_SEED.as_bytes(),
+            bet.player.as_ref(),
+            round.key().as_ref()
+            ],
+        bump = bet.bump)]
+    pub bet: Account<'info, Bet>,
+    /// CHECK: stats of the bet owner, updated once opened
+    #[account(
+        mut,
+        seeds = [
+            PLAYER_STATS_SEED.as_bytes(),
+            bet.player.as_ref(),
+            game.key().as_ref()
+            ],
+        bump)]
+    pub player_stats: UncheckedAccount<'info>,
+    /// CHECK: season entry the stake of the bet counted against, updated once joined
+    #[account(
+        mut,
+        seeds = [
+            SEASON_ENTRY_SEED.as_bytes(),
+            bet.season.as_ref(),
+            bet.player.as_ref()
+            ],
+        bump)]
+    pub season_entry: UncheckedAccount<'info>,
+    #[account(address = bet.season)]
+    pub season: Option<Account<'info, Season>>,
+    /// CHECK: rewards of the referrer of the bet, updated once opened
+    #[account(
+        mut,
+        seeds = [
+            REFERRAL_REWARDS_SEED.as_bytes(),
+            bet.referrer.as_ref(),
+            game.key().as_ref()
+            ],
+        bump)]
+    pub referral_rewards: UncheckedAccount<'info>,
+     #[account(
+        constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        address = game.points_mint
+    )]
+    pub points_mint: Option<Account<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = points_mint,
+        associated_token::authority = player,
+    )]
+    pub points_account: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = round,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = player,
+    )]
+    pub player_vault: Account<'info, TokenAccount>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = spl_associated_token_account::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
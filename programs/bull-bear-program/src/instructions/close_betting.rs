@@ -21,6 +21,14 @@ pub fn close_betting(ctx: Context<CloseBettingContext>) -> Result<()> {
     let clock = Clock::get()?;
     require!((round.start_time + i64::from_ne_bytes((game.round_interval / 2).to_ne_bytes()) ) <= clock.unix_timestamp, BullBearProgramError::BettingPhaseNotEnded);
 
+    // start price is the time-weighted average of the start samples
+    if game.twap_window > 0 {
+        let window_end = round.start_time + game.twap_window as i64;
+        if let Some(twap) = round.start_samples.twap(round.start_time, window_end) {
+            round.start_price = twap;
+        }
+    }
+
     round.betting = BettingStatus::Closed;
 
     msg!("Betting closed.");
@@ -39,7 +47,7 @@ pub struct CloseBettingContext<'info> {
             game_authority.key().as_ref(),
             game.protocol.as_ref(),
             game.token.as_ref(),
-            game.feed_account.as_ref()
+            game.feed_id.as_ref()
         ],
         bump = game.bump
     )]
@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{spl_token, transfer, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::{record_bet_points, record_player_wager, record_referrer, record_season_stake, verify_allowlist};
+
+pub fn commit_bet(ctx: Context<CommitBetContext>, commitment: [u8; 32], amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+
+    let game = &ctx.accounts.game;
+    let round = &mut ctx.accounts.round;
+    let bet = &mut ctx.accounts.bet;
+
+    // check if commit-reveal enabled
+    require!(game.commit_reveal, BullBearProgramError::CommitRevealNotEnabled);
+    // check that no receipt has to be handed out
+    require!(!game.tokenized_bets, BullBearProgramError::BetIsTokenized);
+    // check if player may bet in private games
+    verify_allowlist(game, ctx.accounts.player.key, &proof)?;
+    // check if round active
+    require!(round.status == RoundStatus::Active, BullBearProgramError::RoundNotActive);
+    // check if betting open
+    require!(round.betting == BettingStatus::Open, BullBearProgramError::BettingIsClosed);
+    // check that the bet has a stake
+    require!(amount > 0, BullBearProgramError::InvalidBetAmount);
+
+    // stake joins the pool without a side until revealed
+    round.committed = round.committed.checked_add(amount).ok_or(BullBearProgramError::MaximumBetAmountReached)?;
+    round.num_bets += 1;
+
+    bet.player = ctx.accounts.player.key();
+    bet.round = round.key();
+    bet.outcome = NO_OUTCOME;
+    bet.amount = amount;
+    bet.claimed = false;
+    bet.bump = ctx.bumps.bet;
+    bet.odds_bps = 0;
+    bet.receipt_mint = Pubkey::default();
+    bet.status = BetStatus::Active;
+    bet.commitment = commitment;
+    bet.season = Pubkey::default();
+    bet.referrer = Pubkey::default();
+    bet.points = 0;
+
+    // bets of referred players earn their referrer a share of the settlement fee
+    record_referrer(&ctx.accounts.referral, bet)?;
+
+    // stats are kept for players that opened them, their settled winning streak raises the loyalty points rate
+    let streak = record_player_wager(&ctx.accounts.player_stats, ctx.accounts.last_bet.as_ref(), ctx.accounts.last_round.as_ref(), bet.key(), round.round_nr, amount)?;
+    record_bet_points(game, bet, streak);
+
+    // stakes placed during a season count against its net winnings
+    record_season_stake(game, &ctx.accounts.season_entry, bet, Clock::get()?.unix_timestamp)?;
+
+    // transfer tokens from player to vault
+    let cpi_context = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer{from: ctx.accounts.signer_vault.to_account_info(), to: ctx.accounts.vault.to_account_info(), authority: ctx.accounts.player.to_account_info()}
+    );
+    transfer(cpi_context, amount)?;
+
+    msg!("Bet committed: {:?}", amount);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct CommitBetContext<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED.as_bytes(),
+            game.key().as_ref(),
+            game.counter.to_le_bytes().as_ref(),
+        ],
+        bump = round.bump
+    )]
+    pub round: Account<'info, Round>,
+    #[account(
+        init,
+        payer = player,
+        space = 8 + Bet::INIT_SPACE,
+        seeds = [
+            BET_SEED.as_bytes(),
+            player.key().as_ref(),
+            round.key().as_ref()
+            ],
+        bump)]
+    pub bet: Account<'info, Bet>,
+    /// CHECK: stats of the player, updated once opened
+    #[account(
+        mut,
+        seeds = [
+            PLAYER_STATS_SEED.as_bytes(),
+            player.key().as_ref(),
+            game.key().as_ref()
+            ],
+        bump)]
+    pub player_stats: UncheckedAccount<'info>,
+    pub last_bet: Option<Account<'info, Bet>>,
+    pub last_round: Option<Account<'info, Round>>,
+    /// CHECK: season entry of the player, updated once joined
+    #[account(
+        mut,
+        seeds = [
+            SEASON_ENTRY_SEED.as_bytes(),
+            game.season.as_ref(),
+            player.key().as_ref()
+            ],
+        bump)]
+    pub season_entry: UncheckedAccount<'info>,
+    /// CHECK: referral of the player, read once bound
+    #[account(
+        seeds = [
+            REFERRAL_SEED.as_bytes(),
+            player.key().as_ref()
+            ],
+        bump)]
+    pub referral: UncheckedAccount<'info>,
+     #[account(
+        constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = round,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = player,
+    )]
+    pub signer_vault: Account<'info, TokenAccount>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = spl_associated_token_account::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+
+// ranked season entries are passed as remaining accounts, best first
+pub fn commit_season_ranking(ctx: Context<CommitSeasonRankingContext>) -> Result<()> {
+
+    let game = &ctx.accounts.game;
+    let season = &mut ctx.accounts.season;
+
+    // check game authority
+    require!(game.game_authority == *ctx.accounts.game_authority.key, BullBearProgramError::SignerNotAuthorized);
+
+    // check that the season has ended and the ranking can still change
+    let now = Clock::get()?.unix_timestamp;
+    require!(season.end_time < now, BullBearProgramError::SeasonNotEnded);
+    require!(!season.is_final(now), BullBearProgramError::RankingFinal);
+
+    // check that all paid places are filled
+    let num_ranked = (season.num_winners as u32).min(season.num_entries) as usize;
+    require!(ctx.remaining_accounts.len() == num_ranked, BullBearProgramError::InvalidRanking);
+
+    let mut ranking = [Pubkey::default(); MAX_SEASON_WINNERS];
+    let mut cutoff = i64::MAX;
+    for (rank, account) in ctx.remaining_accounts.iter().enumerate() {
+        require!(*account.owner == crate::ID, BullBearProgramError::InvalidRanking);
+        let entry = SeasonEntry::try_deserialize(&mut &account.data.borrow()[..])?;
+
+        // check that entries belong to the season, appear once and are ordered by net winnings
+        require!(entry.season == season.key()
+            && !ranking[..rank].contains(&entry.player)
+            && entry.net_winnings <= cutoff, BullBearProgramError::InvalidRanking);
+
+        ranking[rank] = entry.player;
+        cutoff = entry.net_winnings;
+    }
+
+    season.ranking = ranking;
+    season.num_ranked = num_ranked as u8;
+    season.ranked_at = now;
+    season.cutoff = cutoff;
+    season.prize_total = ctx.accounts.season_vault.amount;
+
+    msg!("Season ranking committed: {} places", num_ranked);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct CommitSeasonRankingContext<'info> {
+    pub game_authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEASON_SEED.as_bytes(),
+            game.key().as_ref(),
+            season.season_nr.to_le_bytes().as_ref(),
+            ],
+        bump = season.bump)]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = season,
+    )]
+    pub season_vault: Account<'info, TokenAccount>,
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{approve, spl_token, Approve, Mint, Token, TokenAccount};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::require_free_delegate;
+
+pub fn create_auto_bet(ctx: Context<CreateAutoBetContext>, outcome: u8, amount: u64, rounds: u16) -> Result<()> {
+
+    let game = &ctx.accounts.game;
+    let auto_bet = &mut ctx.accounts.auto_bet;
+
+    // check amount and number of rounds
+    require!(amount > 0 && rounds > 0, BullBearProgramError::InvalidAutoBet);
+    // check if outcome exists in the game market
+    require!(outcome < game.num_outcomes(), BullBearProgramError::InvalidPrediction);
+
+    let allowance = amount.checked_mul(rounds as u64).ok_or(BullBearProgramError::InvalidAutoBet)?;
+
+    auto_bet.player = ctx.accounts.player.key();
+    auto_bet.game = game.key();
+    auto_bet.outcome = outcome;
+    auto_bet.amount = amount;
+    auto_bet.remaining = rounds;
+    auto_bet.bump = ctx.bumps.auto_bet;
+
+    // check that no session or signed bets rely on the allowance of the token account
+    require_free_delegate(&ctx.accounts.signer_vault, &auto_bet.key())?;
+
+    // the auto-bet account may spend the stakes of all remaining rounds
+    let cpi_context = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Approve{to: ctx.accounts.signer_vault.to_account_info(), delegate: auto_bet.to_account_info(), authority: ctx.accounts.player.to_account_info()}
+    );
+    approve(cpi_context, allowance)?;
+
+    msg!("Auto-bet created: {} for {} rounds", amount, rounds);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct CreateAutoBetContext<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        init,
+        payer = player,
+        space = 8 + AutoBet::INIT_SPACE,
+        seeds = [
+            AUTO_BET_SEED.as_bytes(),
+            player.key().as_ref(),
+            game.key().as_ref()
+            ],
+        bump)]
+    pub auto_bet: Account<'info, AutoBet>,
+     #[account(
+        constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = player,
+    )]
+    pub signer_vault: Account<'info, TokenAccount>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{spl_token, Mint, Token, TokenAccount};
+use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+
+pub fn create_season(ctx: Context<CreateSeasonContext>, season_nr: u16, start_time: i64, end_time: i64, fee_share_bps: u16, payout_bps: Vec<u16>, challenge_period: u64) -> Result<()> {
+
+    let game = &mut ctx.accounts.game;
+    let season = &mut ctx.accounts.season;
+
+    // check game authority
+    require!(game.game_authority == *ctx.accounts.game_authority.key, BullBearProgramError::SignerNotAuthorized);
+
+    // check that the previous season has ended
+    let now = Clock::get()?.unix_timestamp;
+    require!(game.season_end < now, BullBearProgramError::SeasonActive);
+
+    // check season times, fee share and payouts
+    let payout_total: u64 = payout_bps.iter().map(|bps| *bps as u64).sum();
+    require!(start_time < end_time && now < end_time
+        && (fee_share_bps as u64) <= BPS_DENOMINATOR
+        && !payout_bps.is_empty() && payout_bps.len() <= MAX_SEASON_WINNERS
+        && payout_total <= BPS_DENOMINATOR, BullBearProgramError::InvalidSeason);
+
+    season.game = game.key();
+    season.season_nr = season_nr;
+    season.start_time = start_time;
+    season.end_time = end_time;
+    season.fee_share_bps = fee_share_bps;
+    season.payout_bps = [0; MAX_SEASON_WINNERS];
+    season.payout_bps[..payout_bps.len()].copy_from_slice(&payout_bps);
+    season.num_winners = payout_bps.len() as u8;
+    season.challenge_period = challenge_period;
+    season.num_entries = 0;
+    season.ranking = [Pubkey::default(); MAX_SEASON_WINNERS];
+    season.num_ranked = 0;
+    season.ranked_at = 0;
+    season.cutoff = 0;
+    season.prize_total = 0;
+    season.bump = ctx.bumps.season;
+
+    game.season = season.key();
+    game.season_end = end_time;
+
+    msg!("Season {} created.", season_nr);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+#[instruction(season_nr: u16)]
+pub struct CreateSeasonContext<'info> {
+    #[account(mut)]
+    pub game_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+
+    #[account(
+        init,
+        payer = game_authority,
+        space = 8 + Season::INIT_SPACE,
+        seeds = [
+            SEASON_SEED.as_bytes(),
+            game.key().as_ref(),
+            season_nr.to_le_bytes().as_ref(),
+            ],
+        bump)]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = game_authority,
+        associated_token::mint = mint,
+        associated_token::authority = season,
+    )]
+    pub season_vault: Account<'info, TokenAccount>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = spl_associated_token_account::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{approve, spl_token, Approve, Mint, Token, TokenAccount};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::require_free_delegate;
+
+pub fn create_session(ctx: Context<CreateSessionContext>, session_key: Pubkey, expiry: i64, spend_limit: u64) -> Result<()> {
+
+    let session = &mut ctx.accounts.session;
+
+    // check expiry and spend limit
+    require!(expiry > Clock::get()?.unix_timestamp && spend_limit > 0, BullBearProgramError::InvalidSession);
+
+    session.player = ctx.accounts.player.key();
+    session.session_key = session_key;
+    session.expiry = expiry;
+    session.spend_limit = spend_limit;
+    session.spent = 0;
+    session.bump = ctx.bumps.session;
+
+    // check that no auto-bet or signed bets rely on the allowance of the token account
+    require_free_delegate(&ctx.accounts.signer_vault, &session.key())?;
+
+    // the session account may spend up to the limit on behalf of the player
+    let cpi_context = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Approve{to: ctx.accounts.signer_vault.to_account_info(), delegate: session.to_account_info(), authority: ctx.accounts.player.to_account_info()}
+    );
+    approve(cpi_context, spend_limit)?;
+
+    msg!("Session created: {} until {}", session_key, expiry);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+#[instruction(session_key: Pubkey)]
+pub struct CreateSessionContext<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        init,
+        payer = player,
+        space = 8 + Session::INIT_SPACE,
+        seeds = [
+            SESSION_SEED.as_bytes(),
+            player.key().as_ref(),
+            session_key.as_ref()
+            ],
+        bump)]
+    pub session: Account<'info, Session>,
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = player,
+    )]
+    pub signer_vault: Account<'info, TokenAccount>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
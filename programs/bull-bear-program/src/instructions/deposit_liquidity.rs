@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{mint_to, spl_token, transfer, Mint, MintTo, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::require_between_rounds;
+
+pub fn deposit_liquidity(ctx: Context<DepositLiquidityContext>, amount: u64) -> Result<()> {
+
+    let game = &ctx.accounts.game;
+
+    // check that no round is running (deposits would buy into a known round result)
+    require_between_rounds(&ctx.accounts.round)?;
+
+    // shares are priced on the house funds before the deposit
+    let house_funds = game.house_funds(ctx.accounts.vault.amount);
+    let supply = ctx.accounts.lp_mint.supply;
+    // house funds accrued while the pool was empty go to the game authority first (one share per token)
+    let accrued_shares = if supply == 0 { house_funds } else { 0 };
+    let shares = if supply == 0 {
+        amount
+    } else {
+        (amount as u128 * supply as u128 / house_funds.max(1) as u128) as u64
+    };
+
+    // check that the deposit is worth at least one share (a wiped out pool accepts no deposits)
+    require!(supply == 0 || house_funds > 0, BullBearProgramError::InvalidLiquidityAmount);
+    require!(shares > 0, BullBearProgramError::InvalidLiquidityAmount);
+
+    // transfer tokens from provider to game vault
+    let cpi_context = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer{from: ctx.accounts.provider_vault.to_account_info(), to: ctx.accounts.vault.to_account_info(), authority: ctx.accounts.provider.to_account_info()}
+    );
+    transfer(cpi_context, amount)?;
+
+    // mint shares to game authority and provider
+    let game_authority = game.game_authority;
+    let game_protocol = game.protocol;
+    let game_token = game.token;
+    let game_feed = game.feed_id;
+    let bump = game.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[GAME_SEED.as_bytes(),
+            game_authority.as_ref(),
+            game_protocol.as_ref(),
+            game_token.as_ref(),
+            game_feed.as_ref(),
+            &[bump]]];
+    if accrued_shares > 0 {
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo{mint: ctx.accounts.lp_mint.to_account_info(), to: ctx.accounts.authority_lp_account.to_account_info(), authority: game.to_account_info()},
+            signer_seeds
+        );
+        mint_to(cpi_context, accrued_shares)?;
+    }
+
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo{mint: ctx.accounts.lp_mint.to_account_info(), to: ctx.accounts.provider_lp_account.to_account_info(), authority: game.to_account_info()},
+        signer_seeds
+    );
+    mint_to(cpi_context, shares)?;
+
+    msg!("Liquidity deposited: {} for {} shares", amount, shares);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct DepositLiquidityContext<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref(),
+        ],
+        bump = game.bump,
+        constraint = game.lp_mint != Pubkey::default() @ BullBearProgramError::LiquidityPoolNotInitialized
+    )]
+    pub game: Account<'info, Game>,
+
+    /// CHECK: current round of the game, may not be initialized yet
+    #[account(
+        seeds = [
+            ROUND_SEED.as_bytes(),
+            game.key().as_ref(),
+            game.counter.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub round: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = game,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = game.lp_mint
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = provider,
+    )]
+    pub provider_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = provider,
+    )]
+    pub provider_lp_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = game.game_authority,
+    )]
+    pub authority_lp_account: Account<'info, TokenAccount>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = spl_associated_token_account::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
@@ -1,13 +1,15 @@
 
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
-use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use pyth_solana_receiver_sdk::price_update::{PriceUpdateV2, VerificationLevel};
 
 use anchor_spl::token::{spl_token, transfer, Mint, Token, TokenAccount, Transfer};
 use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
 
 use crate::errors::BullBearProgramError;
+use crate::pricing::move_bps;
 use crate::states::*;
+use crate::utils::get_game_price;
 
 pub fn end_round(ctx: Context<EndRoundContext>) -> Result<()> {
 
@@ -25,49 +27,212 @@ pub fn end_round(ctx: Context<EndRoundContext>) -> Result<()> {
     let clock = Clock::get()?;
     let end_time = clock.unix_timestamp;
     require!((round.end_time) <= end_time, BullBearProgramError::BettingPhaseNotEnded);
+    // check that a running season receives its fee share
+    require!(game.season == Pubkey::default() || game.season_end < end_time
+        || (ctx.accounts.season.is_some() && ctx.accounts.season_vault.is_some()), BullBearProgramError::InvalidSeason);
 
-    let price_update = &mut ctx.accounts.price_update;
-
-    
-    let price = price_update.get_price_no_older_than(
-        &Clock::get()?,
+    let price = get_game_price(&ctx.accounts.price_update, &game.feed_id, game,
         MAXIMUM_AGE,
-        &game.feed_id,
-    )?;
+        VerificationLevel::Full)?;
     
-    let sol_price = price.price;
+    let mut sol_price = price.price;
+
+    // end price is the time-weighted average of the end samples
+    if game.twap_window > 0 {
+        let window_start = round.end_time - game.twap_window as i64;
+        let samples = &mut round.end_samples;
+        if samples.last().map_or(true, |last| last.publish_time < price.publish_time) {
+            samples.push(PriceSample{price: price.price, publish_time: price.publish_time});
+        }
+        sol_price = samples.twap(window_start, end_time).unwrap_or(price.price);
+    }
 
     round.end_price = sol_price;
     round.end_time = end_time;
-    if round.start_price < sol_price {
+
+    // head-to-head games compare the relative performance of both feeds
+    let start_price = round.start_price as i128;
+    let (price_diff, flat_band) = if game.market == MarketType::HeadToHead {
+        let price_update_b = ctx.accounts.price_update_b.as_ref().ok_or(BullBearProgramError::MissingSecondFeed)?;
+        let price_b = get_game_price(price_update_b, &game.feed_id_b, game,
+            MAXIMUM_AGE,
+            VerificationLevel::Full)?;
+        round.end_price_b = price_b.price;
+
+        let start_price_b = round.start_price_b as i128;
+        let price_diff = (sol_price as i128 - start_price) * start_price_b - (price_b.price as i128 - start_price_b) * start_price;
+        let flat_band = start_price.unsigned_abs() * start_price_b.unsigned_abs() * game.flat_band_bps as u128 / BPS_DENOMINATOR as u128;
+        (price_diff, flat_band)
+    } else {
+        let price_diff = sol_price as i128 - start_price;
+        let flat_band = start_price.unsigned_abs() * game.flat_band_bps as u128 / BPS_DENOMINATOR as u128;
+        (price_diff, flat_band)
+    };
+
+    // moves inside the flat band settle as no change
+    if price_diff.unsigned_abs() > flat_band && price_diff > 0 {
         round.result = PriceMovement::Bull;
     }
-    else if round.start_price > sol_price {
+    else if price_diff.unsigned_abs() > flat_band && price_diff < 0 {
         round.result = PriceMovement::Bear;
     }
     else {
         round.result = PriceMovement::NoChange;
+    }
+
+    // determine winning outcome of the market
+    let winning_outcome = match game.market {
+        MarketType::Buckets => {
+            game.bucket_of(move_bps(round.start_price, sol_price))
+        }
+        _ => match round.result {
+            PriceMovement::Bull => OUTCOME_BULL,
+            PriceMovement::Bear => OUTCOME_BEAR,
+            _ => OUTCOME_FLAT,
+        },
+    };
+
+    // no change and buckets only win with bets or seeds on them, otherwise the flat policy applies
+    let winning_stakes = round.totals[winning_outcome as usize] + round.seeds[winning_outcome as usize];
+    let has_winner = match game.market {
+        MarketType::Buckets => winning_stakes > 0,
+        _ => winning_outcome != OUTCOME_FLAT || winning_stakes > 0,
+    };
+
+    let game_id = game.key();
+    let round_ref = round.round_nr.to_le_bytes();
+    let round_bump = round.bump;
+    let round_seeds: &[&[&[u8]]] = &[&[ROUND_SEED.as_bytes(),
+        game_id.as_ref(),
+        round_ref.as_ref(), &[round_bump]]];
+    let seed_total: u64 = round.seeds.iter().sum();
+    let exited_total: u64 = round.exited.iter().sum();
+
+    // stakes of bets that were never revealed are forfeited
+    if round.committed > 0 {
+        require!(game.reveal_deadline(round.start_time) < end_time, BullBearProgramError::RevealPhaseNotEnded);
+
+        match game.unrevealed_policy {
+            UnrevealedPolicy::House => {
+                let cpi_context = CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer{from: ctx.accounts.round_vault.to_account_info(), to: ctx.accounts.game_vault.to_account_info(), authority: round.to_account_info()},
+                    round_seeds
+                );
+                transfer(cpi_context, round.committed)?;
+                ctx.accounts.round_vault.reload()?;
+            }
+            UnrevealedPolicy::Pool => {
+                round.bonus += round.committed;
+            }
+        }
+        round.committed = 0;
+    }
 
-        // get token balance
-        let amount = ctx.accounts.round_vault.amount;
+    if has_winner {
+        round.winning_outcome = winning_outcome;
+
+        // parimutuel rounds pay a settlement fee on the losing stakes, a running season takes its share into the prize vault
+        if !game.fixed_odds && game.settlement_fee_bps > 0 {
+            let losing_stakes = round.prize_pool() - winning_stakes;
+            round.fee = (losing_stakes as u128 * game.settlement_fee_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+
+            let season_share = match &ctx.accounts.season {
+                Some(season) if season.is_live(end_time) => (round.fee as u128 * season.fee_share_bps as u128 / BPS_DENOMINATOR as u128) as u64,
+                _ => 0,
+            };
+            if season_share > 0 {
+                let season_vault = ctx.accounts.season_vault.as_ref().ok_or(BullBearProgramError::InvalidSeason)?;
+                let cpi_context = CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer{from: ctx.accounts.round_vault.to_account_info(), to: season_vault.to_account_info(), authority: round.to_account_info()},
+                    round_seeds
+                );
+                transfer(cpi_context, season_share)?;
+                round.season_fee = season_share;
+            }
+            if round.fee > season_share {
+                let cpi_context = CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer{from: ctx.accounts.round_vault.to_account_info(), to: ctx.accounts.game_vault.to_account_info(), authority: round.to_account_info()},
+                    round_seeds
+                );
+                transfer(cpi_context, round.fee - season_share)?;
+            }
+        }
+
+        // house seeds and positions taken over by the house take their share of the pool back to the game vault
+        let house_stakes = round.seeds[winning_outcome as usize] + round.exited[winning_outcome as usize];
+        let house_share = round.prize_share(house_stakes, winning_outcome);
+        if house_share > 0 {
+            let cpi_context = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer{from: ctx.accounts.round_vault.to_account_info(), to: ctx.accounts.game_vault.to_account_info(), authority: round.to_account_info()},
+                round_seeds
+            );
+            transfer(cpi_context, house_share)?;
+        }
+
+        // fixed-odds rounds settle the house result with the game vault, the round vault keeps the payouts
+        if game.fixed_odds {
+            let payout = round.payouts[winning_outcome as usize];
+            let balance = ctx.accounts.round_vault.amount;
+
+            if balance > payout {
+                let cpi_context = CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer{from: ctx.accounts.round_vault.to_account_info(), to: ctx.accounts.game_vault.to_account_info(), authority: round.to_account_info()},
+                    round_seeds
+                );
+                transfer(cpi_context, balance - payout)?;
+            }
+            else if payout > balance {
+                let game_authority = game.game_authority;
+                let game_protocol = game.protocol;
+                let game_token = game.token;
+                let game_feed = game.feed_id;
+                let bump = game.bump;
+                let signer_seeds: &[&[&[u8]]] = &[&[GAME_SEED.as_bytes(),
+                    game_authority.as_ref(),
+                    game_protocol.as_ref(),
+                    game_token.as_ref(),
+                    game_feed.as_ref(),
+                    &[bump]]];
+                let cpi_context = CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer{from: ctx.accounts.game_vault.to_account_info(), to: ctx.accounts.round_vault.to_account_info(), authority: game.to_account_info()},
+                    signer_seeds
+                );
+                transfer(cpi_context, payout - balance)?;
+            }
+        }
+    }
+    else {
+        round.flat_policy = game.flat_policy.clone();
+
+        // get token balance (refunds keep the player stakes in the round vault, house funds move on)
+        let amount = match game.flat_policy {
+            FlatPolicy::Refund => round.bonus + seed_total + exited_total,
+            _ => ctx.accounts.round_vault.amount,
+        };
 
         // transfer tokens back to game vault
-        let game_id = game.key();
-        let round_ref = round.round_nr.to_le_bytes();
-        let bump = round.bump;
-        let signer_seeds: &[&[&[u8]]] = &[&[ROUND_SEED.as_bytes(),
-            game_id.as_ref(),
-            round_ref.as_ref(), &[bump]]];
         let cpi_context = CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             Transfer{from: ctx.accounts.round_vault.to_account_info(), to: ctx.accounts.game_vault.to_account_info(), authority: round.to_account_info()},
-            signer_seeds
+            round_seeds
         );
         
         transfer(cpi_context, amount)?;
+
+        // rolled over funds are added to the next round, house seeds and exited stakes are returned to the house
+        if game.flat_policy != FlatPolicy::HouseTake {
+            game.rollover += amount - seed_total - exited_total;
+        }
     }
     
     round.status = RoundStatus::Ended;
+    game.exposure = 0;
     game.counter += 1;
 
     msg!("Round ended with: {:?}", sol_price);
@@ -87,7 +252,7 @@ pub struct EndRoundContext<'info> {
             game_authority.key().as_ref(),
             game.protocol.as_ref(),
             game.token.as_ref(),
-            game.feed_account.as_ref()
+            game.feed_id.as_ref()
         ],
         bump = game.bump
     )]
@@ -122,9 +287,21 @@ pub struct EndRoundContext<'info> {
     )]
     pub game_vault: Account<'info, TokenAccount>,
 
+    #[account(address = game.season)]
+    pub season: Option<Account<'info, Season>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = season_vault.owner == game.season @ BullBearProgramError::InvalidSeason
+    )]
+    pub season_vault: Option<Account<'info, TokenAccount>>,
+
     #[account(address = game.feed_account)]
     pub price_update: Account<'info, PriceUpdateV2>,
 
+    #[account(address = game.feed_account_b)]
+    pub price_update_b: Option<Account<'info, PriceUpdateV2>>,
+
     #[account(address = spl_token::ID)]
     pub token_program: Program<'info, Token>,
 
@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use pyth_solana_receiver_sdk::price_update::{PriceUpdateV2, VerificationLevel};
+use anchor_spl::token::{spl_token, transfer, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
+
+use crate::errors::BullBearProgramError;
+use crate::pricing::{exit_value, move_bps};
+use crate::states::*;
+use crate::utils::{get_game_price, mint_bet_points, record_season_return};
+
+pub fn exit_bet(ctx: Context<ExitBetContext>) -> Result<()> {
+
+    let game = &mut ctx.accounts.game;
+    let round = &mut ctx.accounts.round;
+    let bet = &mut ctx.accounts.bet;
+
+    // check if authorized
+    require!(bet.player == *ctx.accounts.player.key, BullBearProgramError::SignerNotAuthorized);
+    // check that the position was not handed out as a receipt
+    require!(!bet.is_tokenized(), BullBearProgramError::BetIsTokenized);
+    // check if position still belongs to the player
+    require!(bet.status == BetStatus::Active, BullBearProgramError::BetExited);
+    // check if early exit enabled
+    require!(game.early_exit, BullBearProgramError::EarlyExitNotEnabled);
+    // check that the position is a directional bet
+    require!((game.market == MarketType::TwoWay || game.market == MarketType::ThreeWay)
+        && (bet.outcome == OUTCOME_BULL || bet.outcome == OUTCOME_BEAR), BullBearProgramError::EarlyExitNotSupported);
+
+    // check if round active
+    require!(round.status == RoundStatus::Active, BullBearProgramError::RoundNotActive);
+    // check if betting closed (open bets can be cancelled)
+    require!(round.betting == BettingStatus::Closed, BullBearProgramError::BettingNeedsToBeClosed);
+    // check if round still running
+    let clock = Clock::get()?;
+    require!(clock.unix_timestamp < round.end_time, BullBearProgramError::BettingPhaseNotEnded);
+
+    let price = get_game_price(&ctx.accounts.price_update, &game.feed_id, game,
+        MAXIMUM_AGE,
+        VerificationLevel::Partial{num_signatures: 1})?;
+
+    // payout at settlement and the win probability implied at placement
+    let outcome = bet.outcome as usize;
+    let (payout, implied_bps) = if bet.odds_bps > 0 {
+        (bet.fixed_payout(), BPS_DENOMINATOR * BPS_DENOMINATOR / bet.odds_bps as u64)
+    } else {
+        let winning_stakes = round.totals[outcome] + round.seeds[outcome];
+        (round.prize_share(bet.amount, bet.outcome), (winning_stakes as u128 * BPS_DENOMINATOR as u128 / round.prize_pool() as u128) as u64)
+    };
+    let value = exit_value(payout, implied_bps, bet.outcome, move_bps(round.start_price, price.price), game.exit_spread_bps);
+
+    // check that the house can take over the position
+    require!(game.house_funds(ctx.accounts.game_vault.amount) >= value, BullBearProgramError::InsufficientHouseFunds);
+
+    // house takes over the position (fixed-odds bets no longer owe a payout)
+    if bet.odds_bps > 0 {
+        round.payouts[outcome] -= bet.fixed_payout();
+        game.exposure = round.house_exposure();
+    } else {
+        round.exited[outcome] += bet.amount;
+    }
+    bet.status = BetStatus::Exited;
+
+    // the exit value of a season stake counts towards its net winnings
+    record_season_return(ctx.accounts.season.as_ref(), &ctx.accounts.season_entry, bet, value)?;
+
+    // loyalty points of the stake are minted once the bet is exited (it never settles)
+    mint_bet_points(game, round, bet, ctx.accounts.points_mint.as_ref(), ctx.accounts.points_account.as_ref(), &ctx.accounts.token_program)?;
+
+    // transfer exit value from game vault to player
+    if value > 0 {
+        let game_authority = game.game_authority;
+        let game_protocol = game.protocol;
+        let game_token = game.token;
+        let game_feed = game.feed_id;
+        let bump = game.bump;
+        let signer_seeds: &[&[&[u8]]] = &[&[GAME_SEED.as_bytes(),
+                game_authority.as_ref(),
+                game_protocol.as_ref(),
+                game_token.as_ref(),
+                game_feed.as_ref(),
+                &[bump]]];
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer{from: ctx.accounts.game_vault.to_account_info(), to: ctx.accounts.signer_vault.to_account_info(), authority: game.to_account_info()},
+            signer_seeds
+        );
+        transfer(cpi_context, value)?;
+    }
+
+    msg!("Bet exited: {}", value);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct ExitBetContext<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED.as_bytes(),
+            game.key().as_ref(),
+            game.counter.to_le_bytes().as_ref(),
+        ],
+        bump = round.bump
+    )]
+    pub round: Account<'info, Round>,
+    #[account(
+        mut,
+        seeds = [
+            BET_SEED.as_bytes(),
+            player.key().as_ref(),
+            round.key().as_ref()
+            ],
+        bump = bet.bump)]
+    pub bet: Account<'info, Bet>,
+    /// CHECK: season entry the stake of the bet counted against, updated once joined
+    #[account(
+        mut,
+        seeds = [
+            SEASON_ENTRY_SEED.as_bytes(),
+            bet.season.as_ref(),
+            bet.player.as_ref()
+            ],
+        bump)]
+    pub season_entry: UncheckedAccount<'info>,
+    #[account(address = bet.season)]
+    pub season: Option<Account<'info, Season>>,
+
+    #[account(address = game.feed_account)]
+    pub price_update: Account<'info, PriceUpdateV2>,
+
+     #[account(
+        constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = game,
+    )]
+    pub game_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = player,
+    )]
+    pub signer_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = game.points_mint
+    )]
+    pub points_mint: Option<Account<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = points_mint,
+        associated_token::authority = player,
+    )]
+    pub points_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = spl_associated_token_account::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
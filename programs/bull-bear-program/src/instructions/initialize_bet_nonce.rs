@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+use crate::states::*;
+
+pub fn initialize_bet_nonce(ctx: Context<InitializeBetNonceContext>) -> Result<()> {
+
+    let bet_nonce = &mut ctx.accounts.bet_nonce;
+
+    bet_nonce.player = ctx.accounts.player.key();
+    bet_nonce.nonce = 0;
+    bet_nonce.bump = ctx.bumps.bet_nonce;
+
+    msg!("Bet nonce initialized.");
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct InitializeBetNonceContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: player signing bet intents
+    pub player: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BetNonce::INIT_SPACE,
+        seeds = [
+            BET_NONCE_SEED.as_bytes(),
+            player.key().as_ref()
+            ],
+        bump)]
+    pub bet_nonce: Account<'info, BetNonce>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
@@ -2,9 +2,11 @@ use anchor_lang::{prelude::*, solana_program};
 use anchor_lang::solana_program::{system_instruction, system_program};
 use anchor_spl::token::{spl_token, Mint, Token, TokenAccount};
 use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
-use pyth_solana_receiver_sdk::price_update::get_feed_id_from_hex;
+use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, PriceUpdateV2};
+use core::cmp::max;
 
 use crate::states::*;
+use crate::utils::validate_price_feed;
 
 pub fn initialize_game(ctx: Context<InitializeGameContext>, interval: u64, feed_id: String, feed_account: Pubkey) -> Result<()> {
     
@@ -12,16 +14,54 @@ pub fn initialize_game(ctx: Context<InitializeGameContext>, interval: u64, feed_
     let protocol = &ctx.accounts.protocol;
     let initialized_game = &mut ctx.accounts.game;
 
+    // check that the price account carries the requested feed with a fresh price
+    let feed_id = get_feed_id_from_hex(&feed_id)?;
+    validate_price_feed(&ctx.accounts.price_update, &feed_id, max(MAXIMUM_AGE, interval))?;
+
     initialized_game.protocol = protocol.key();
     initialized_game.game_authority = game_authority.key();
     initialized_game.counter = 0;
     initialized_game.round_interval = interval;
 
-    initialized_game.feed_id = get_feed_id_from_hex(&feed_id)?;
+    initialized_game.feed_id = feed_id;
     initialized_game.feed_account = feed_account;
     initialized_game.vault = ctx.accounts.vault.key();
     initialized_game.token = ctx.accounts.mint.key();
     initialized_game.bump = ctx.bumps.game;
+    initialized_game.legacy_game = Pubkey::default();
+    initialized_game.twap_window = 0;
+    initialized_game.price_source = PriceSource::Spot;
+    initialized_game.max_ema_deviation_bps = 0;
+    initialized_game.flat_band_bps = 0;
+    initialized_game.flat_policy = FlatPolicy::HouseTake;
+    initialized_game.rollover = 0;
+    initialized_game.market = MarketType::TwoWay;
+    initialized_game.num_buckets = 0;
+    initialized_game.feed_account_b = Pubkey::default();
+    initialized_game.fixed_odds = false;
+    initialized_game.max_exposure_bps = 0;
+    initialized_game.exposure = 0;
+    initialized_game.lp_mint = Pubkey::default();
+    initialized_game.queued_shares = 0;
+    initialized_game.seed_amount = 0;
+    initialized_game.cancel_penalty_bps = 0;
+    initialized_game.switch_fee_bps = 0;
+    initialized_game.tokenized_bets = false;
+    initialized_game.early_exit = false;
+    initialized_game.exit_spread_bps = 0;
+    initialized_game.allowlist_root = [0; 32];
+    initialized_game.commit_reveal = false;
+    initialized_game.reveal_window = 0;
+    initialized_game.unrevealed_policy = UnrevealedPolicy::House;
+    initialized_game.settlement_fee_bps = 0;
+    initialized_game.season = Pubkey::default();
+    initialized_game.season_end = 0;
+    initialized_game.referral_share_bps = 0;
+    initialized_game.referral_liability = 0;
+    initialized_game.points_mint = Pubkey::default();
+    initialized_game.points_enabled = false;
+    initialized_game.points_rate_bps = 0;
+    initialized_game.streak_bonus_bps = 0;
 
     // Transfer SOL from game_authority to protocol
     let ix = system_instruction::transfer(&game_authority.key(), &protocol.key(), protocol.game_fee);
@@ -55,17 +95,19 @@ pub struct InitializeGameContext<'info> {
     )]
     pub protocol: Account<'info, Protocol>,
 
+    #[account(address = feed_account)]
+    pub price_update: Account<'info, PriceUpdateV2>,
+
     #[account(
         init,
         payer = game_authority,
         space = 8 + Game::INIT_SPACE,
         seeds = [
-            // this should include the token address
             GAME_SEED.as_bytes(),
             game_authority.key().as_ref(),
             protocol.key().as_ref(),
             mint.key().as_ref(),
-            feed_account.as_ref(),
+            price_update.price_message.feed_id.as_ref(),
             ],
         bump)]
     pub game: Account<'info, Game>,
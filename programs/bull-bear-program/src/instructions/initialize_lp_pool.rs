@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{mint_to, spl_token, Mint, MintTo, Token, TokenAccount};
+use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::require_between_rounds;
+
+pub fn initialize_lp_pool(ctx: Context<InitializeLpPoolContext>) -> Result<()> {
+
+    let game = &mut ctx.accounts.game;
+
+    // check game authority
+    require!(game.game_authority == *ctx.accounts.game_authority.key, BullBearProgramError::SignerNotAuthorized);
+    // check that the pool does not exist yet
+    require!(game.lp_mint == Pubkey::default(), BullBearProgramError::LiquidityPoolInitialized);
+
+    // check that no round is running
+    require_between_rounds(&ctx.accounts.round)?;
+
+    game.lp_mint = ctx.accounts.lp_mint.key();
+    game.queued_shares = 0;
+
+    // existing house funds become the game authority's position (one share per token)
+    let shares = game.house_funds(ctx.accounts.vault.amount);
+    if shares > 0 {
+        let game_authority = game.game_authority;
+        let game_protocol = game.protocol;
+        let game_token = game.token;
+        let game_feed = game.feed_id;
+        let bump = game.bump;
+        let signer_seeds: &[&[&[u8]]] = &[&[GAME_SEED.as_bytes(),
+                game_authority.as_ref(),
+                game_protocol.as_ref(),
+                game_token.as_ref(),
+                game_feed.as_ref(),
+                &[bump]]];
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo{mint: ctx.accounts.lp_mint.to_account_info(), to: ctx.accounts.authority_lp_account.to_account_info(), authority: game.to_account_info()},
+            signer_seeds
+        );
+        mint_to(cpi_context, shares)?;
+    }
+
+    msg!("Liquidity pool initialized: {}", shares);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct InitializeLpPoolContext<'info> {
+    #[account(mut)]
+    pub game_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref(),
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+
+    /// CHECK: current round of the game, may not be initialized yet
+    #[account(
+        seeds = [
+            ROUND_SEED.as_bytes(),
+            game.key().as_ref(),
+            game.counter.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub round: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = game,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = game_authority,
+        mint::decimals = mint.decimals,
+        mint::authority = game,
+        seeds = [
+            LP_MINT_SEED.as_bytes(),
+            game.key().as_ref(),
+        ],
+        bump)]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = game_authority,
+        associated_token::mint = lp_mint,
+        associated_token::authority = game,
+    )]
+    pub lp_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = game_authority,
+        associated_token::mint = lp_mint,
+        associated_token::authority = game_authority,
+    )]
+    pub authority_lp_account: Account<'info, TokenAccount>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = spl_associated_token_account::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+use crate::states::*;
+
+pub fn initialize_player_stats(ctx: Context<InitializePlayerStatsContext>) -> Result<()> {
+
+    let player_stats = &mut ctx.accounts.player_stats;
+
+    player_stats.player = ctx.accounts.player.key();
+    player_stats.game = ctx.accounts.game.key();
+    player_stats.total_wagered = 0;
+    player_stats.total_won = 0;
+    player_stats.wins = 0;
+    player_stats.losses = 0;
+    player_stats.current_streak = 0;
+    player_stats.best_streak = 0;
+    player_stats.last_round = 0;
+    player_stats.previous_round = 0;
+    player_stats.last_bet = Pubkey::default();
+    player_stats.bump = ctx.bumps.player_stats;
+
+    msg!("Player stats initialized.");
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct InitializePlayerStatsContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: player the stats are kept for
+    pub player: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PlayerStats::INIT_SPACE,
+        seeds = [
+            PLAYER_STATS_SEED.as_bytes(),
+            player.key().as_ref(),
+            game.key().as_ref()
+            ],
+        bump)]
+    pub player_stats: Account<'info, PlayerStats>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{spl_token, Mint, Token};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+
+pub fn initialize_points(ctx: Context<InitializePointsContext>) -> Result<()> {
+
+    let game = &mut ctx.accounts.game;
+
+    // check game authority
+    require!(game.game_authority == *ctx.accounts.game_authority.key, BullBearProgramError::SignerNotAuthorized);
+    // check that the points mint does not exist yet
+    require!(game.points_mint == Pubkey::default(), BullBearProgramError::PointsInitialized);
+
+    // points are minted by the game, they are enabled separately
+    game.points_mint = ctx.accounts.points_mint.key();
+    game.points_enabled = false;
+
+    msg!("Loyalty points initialized: {}", game.points_mint);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct InitializePointsContext<'info> {
+    #[account(mut)]
+    pub game_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref(),
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+
+    #[account(
+        constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = game_authority,
+        mint::decimals = mint.decimals,
+        mint::authority = game,
+        seeds = [
+            POINTS_MINT_SEED.as_bytes(),
+            game.key().as_ref(),
+        ],
+        bump)]
+    pub points_mint: Account<'info, Mint>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+use crate::states::*;
+
+pub fn initialize_referral_rewards(ctx: Context<InitializeReferralRewardsContext>) -> Result<()> {
+
+    let referral_rewards = &mut ctx.accounts.referral_rewards;
+
+    referral_rewards.referrer = ctx.accounts.referrer.key();
+    referral_rewards.game = ctx.accounts.game.key();
+    referral_rewards.accrued = 0;
+    referral_rewards.claimed = 0;
+    referral_rewards.bump = ctx.bumps.referral_rewards;
+
+    msg!("Referral rewards initialized.");
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct InitializeReferralRewardsContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: referrer the rewards accrue to
+    pub referrer: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ReferralRewards::INIT_SPACE,
+        seeds = [
+            REFERRAL_REWARDS_SEED.as_bytes(),
+            referrer.key().as_ref(),
+            game.key().as_ref()
+            ],
+        bump)]
+    pub referral_rewards: Account<'info, ReferralRewards>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
-use anchor_spl::token::{spl_token, Mint, Token, TokenAccount};
+use anchor_spl::token::{spl_token, transfer, Mint, Token, TokenAccount, Transfer};
 use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
 
 use crate::errors::BullBearProgramError;
@@ -21,6 +21,32 @@ pub fn initialize_round(ctx: Context<InitializeRoundContext>) -> Result<()> {
     initialized_round.result = PriceMovement::None;
     initialized_round.status = RoundStatus::Inactive;
     initialized_round.bump = ctx.bumps.round;
+    initialized_round.flat_policy = FlatPolicy::HouseTake;
+    initialized_round.winning_outcome = NO_OUTCOME;
+
+    // funds rolled over from flat rounds join the prize pool
+    if game.rollover > 0 {
+        let game_authority = game.game_authority;
+        let game_protocol = game.protocol;
+        let game_token = game.token;
+        let game_feed = game.feed_id;
+        let bump = game.bump;
+        let signer_seeds: &[&[&[u8]]] = &[&[GAME_SEED.as_bytes(),
+                game_authority.as_ref(),
+                game_protocol.as_ref(),
+                game_token.as_ref(),
+                game_feed.as_ref(),
+                &[bump]]];
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer{from: ctx.accounts.game_vault.to_account_info(), to: ctx.accounts.vault.to_account_info(), authority: game.to_account_info()},
+            signer_seeds
+        );
+        transfer(cpi_context, game.rollover)?;
+
+        initialized_round.bonus = game.rollover;
+        game.rollover = 0;
+    }
 
     msg!("Round {} initalized.", initialized_round.round_nr.to_string());
 
@@ -34,12 +60,13 @@ pub struct InitializeRoundContext<'info> {
     pub game_authority: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [
             GAME_SEED.as_bytes(),
             game_authority.key().as_ref(),
             game.protocol.as_ref(),
             game.token.as_ref(),
-            game.feed_account.as_ref()
+            game.feed_id.as_ref()
         ],
         bump = game.bump,
     )]
@@ -70,6 +97,13 @@ pub struct InitializeRoundContext<'info> {
     )]
     pub vault: Account<'info, TokenAccount>,
 
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = game,
+    )]
+    pub game_vault: Account<'info, TokenAccount>,
+
     #[account(address = spl_token::ID)]
     pub token_program: Program<'info, Token>,
 
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+
+pub fn join_season(ctx: Context<JoinSeasonContext>) -> Result<()> {
+
+    let season = &mut ctx.accounts.season;
+    let season_entry = &mut ctx.accounts.season_entry;
+
+    // check that the season has not ended
+    require!(Clock::get()?.unix_timestamp <= season.end_time, BullBearProgramError::SeasonEnded);
+
+    season_entry.season = season.key();
+    season_entry.player = ctx.accounts.player.key();
+    season_entry.start_time = season.start_time;
+    season_entry.end_time = season.end_time;
+    season_entry.net_winnings = 0;
+    season_entry.claimed = false;
+    season_entry.bump = ctx.bumps.season_entry;
+
+    season.num_entries += 1;
+
+    msg!("Season joined.");
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct JoinSeasonContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: player the entry is kept for
+    pub player: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [
+            SEASON_SEED.as_bytes(),
+            game.key().as_ref(),
+            season.season_nr.to_le_bytes().as_ref(),
+            ],
+        bump = season.bump)]
+    pub season: Account<'info, Season>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SeasonEntry::INIT_SPACE,
+        seeds = [
+            SEASON_ENTRY_SEED.as_bytes(),
+            season.key().as_ref(),
+            player.key().as_ref()
+            ],
+        bump)]
+    pub season_entry: Account<'info, SeasonEntry>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
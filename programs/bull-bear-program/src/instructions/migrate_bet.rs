@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_lang::solana_program::system_program;
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::{read_legacy_account, rewrite_account};
+
+pub fn migrate_bet(ctx: Context<MigrateBetContext>) -> Result<()> {
+
+    let bet_info = ctx.accounts.bet.to_account_info();
+
+    // check legacy bet layout
+    let legacy_bet: LegacyBet = read_legacy_account(&bet_info, &Bet::DISCRIMINATOR, LegacyBet::INIT_SPACE)?;
+
+    // check that the bet is seeded by its player and round
+    let (bet_key, _) = Pubkey::find_program_address(&[BET_SEED.as_bytes(),
+        legacy_bet.player.as_ref(),
+        legacy_bet.round.as_ref()], ctx.program_id);
+    require!(bet_key == bet_info.key(), BullBearProgramError::InvalidLegacyAccount);
+
+    let bet = legacy_bet.into_bet();
+    rewrite_account(&bet_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &bet,
+        Bet::INIT_SPACE)?;
+
+    msg!("Bet migrated: {}", bet.amount);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct MigrateBetContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: bet in the legacy layout, layout and seeds are checked in the instruction
+    #[account(
+        mut,
+        owner = crate::ID @ BullBearProgramError::InvalidLegacyAccount,
+    )]
+    pub bet: UncheckedAccount<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
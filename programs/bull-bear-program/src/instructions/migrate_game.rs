@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{close_account, spl_token, transfer, CloseAccount, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::{read_legacy_account, require_between_rounds};
+
+pub fn migrate_game(ctx: Context<MigrateGameContext>) -> Result<()> {
+
+    let legacy_info = ctx.accounts.legacy_game.to_account_info();
+
+    // check legacy game layout
+    let legacy_game = {
+        let data = legacy_info.try_borrow_data()?;
+        require!(data.len() > 8 && data[..8] == Game::DISCRIMINATOR, BullBearProgramError::InvalidLegacyGame);
+        LegacyGame::deserialize(&mut &data[8..])?
+    };
+
+    // check game authority
+    require!(legacy_game.game_authority == *ctx.accounts.game_authority.key, BullBearProgramError::SignerNotAuthorized);
+    // check that the legacy game is seeded by its stored feed account (the account itself may be gone)
+    let legacy_key = Pubkey::create_program_address(&[GAME_SEED.as_bytes(),
+        legacy_game.game_authority.as_ref(),
+        legacy_game.protocol.as_ref(),
+        legacy_game.token.as_ref(),
+        legacy_game.feed_account.as_ref(),
+        &[legacy_game.bump]], ctx.program_id).map_err(|_| BullBearProgramError::InvalidLegacyGame)?;
+    require!(legacy_key == legacy_info.key()
+        && legacy_game.protocol == ctx.accounts.protocol.key()
+        && legacy_game.token == ctx.accounts.mint.key(), BullBearProgramError::InvalidLegacyGame);
+    // check that the current feed account carries the game feed
+    require!(legacy_game.feed_id == ctx.accounts.price_update.price_message.feed_id, BullBearProgramError::FeedIdMismatch);
+
+    // check that no round of the legacy game is running
+    let (legacy_round, _) = Pubkey::find_program_address(&[ROUND_SEED.as_bytes(),
+        legacy_info.key.as_ref(),
+        legacy_game.counter.to_le_bytes().as_ref()], ctx.program_id);
+    require!(legacy_round == ctx.accounts.legacy_round.key(), BullBearProgramError::InvalidLegacyGame);
+    // the round may still be in the legacy layout
+    let round_info = ctx.accounts.legacy_round.to_account_info();
+    if round_info.data_len() == 8 + LegacyRound::INIT_SPACE {
+        let round: LegacyRound = read_legacy_account(&round_info, &Round::DISCRIMINATOR, LegacyRound::INIT_SPACE)?;
+        require!(round.status != RoundStatus::Active, BullBearProgramError::RoundInProgress);
+    } else {
+        require_between_rounds(&round_info)?;
+    }
+
+    // copy game state, round numbering continues where the legacy game stopped
+    let game = &mut ctx.accounts.game;
+    game.protocol = legacy_game.protocol;
+    game.game_authority = legacy_game.game_authority;
+    game.counter = legacy_game.counter;
+    game.round_interval = legacy_game.round_interval;
+    game.feed_id = legacy_game.feed_id;
+    game.feed_account = ctx.accounts.price_update.key();
+    game.vault = ctx.accounts.vault.key();
+    game.token = legacy_game.token;
+    game.bump = ctx.bumps.game;
+    // rounds of the legacy game stay claimable through the migrated game
+    game.legacy_game = legacy_info.key();
+    game.twap_window = 0;
+    game.price_source = PriceSource::Spot;
+    game.max_ema_deviation_bps = 0;
+    game.flat_band_bps = 0;
+    game.flat_policy = FlatPolicy::HouseTake;
+    game.rollover = 0;
+    game.market = MarketType::TwoWay;
+    game.num_buckets = 0;
+    game.feed_account_b = Pubkey::default();
+    game.fixed_odds = false;
+    game.max_exposure_bps = 0;
+    game.exposure = 0;
+    game.lp_mint = Pubkey::default();
+    game.queued_shares = 0;
+    game.seed_amount = 0;
+    game.cancel_penalty_bps = 0;
+    game.switch_fee_bps = 0;
+    game.tokenized_bets = false;
+    game.early_exit = false;
+    game.exit_spread_bps = 0;
+    game.allowlist_root = [0; 32];
+    game.commit_reveal = false;
+    game.reveal_window = 0;
+    game.unrevealed_policy = UnrevealedPolicy::House;
+    game.settlement_fee_bps = 0;
+    game.season = Pubkey::default();
+    game.season_end = 0;
+    game.referral_share_bps = 0;
+    game.referral_liability = 0;
+    game.points_mint = Pubkey::default();
+    game.points_enabled = false;
+    game.points_rate_bps = 0;
+    game.streak_bonus_bps = 0;
+
+    // move funds from legacy vault to game vault
+    let game_authority = *ctx.accounts.game_authority.key;
+    let bump = legacy_game.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[GAME_SEED.as_bytes(),
+            game_authority.as_ref(),
+            legacy_game.protocol.as_ref(),
+            legacy_game.token.as_ref(),
+            legacy_game.feed_account.as_ref(),
+            &[bump]]];
+
+    let amount = ctx.accounts.legacy_vault.amount;
+    if amount > 0 {
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer{from: ctx.accounts.legacy_vault.to_account_info(), to: ctx.accounts.vault.to_account_info(), authority: legacy_info.clone()},
+            signer_seeds
+        );
+        transfer(cpi_context, amount)?;
+    }
+
+    // close legacy vault
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount{account: ctx.accounts.legacy_vault.to_account_info(), destination: ctx.accounts.game_authority.to_account_info(), authority: legacy_info.clone()},
+        signer_seeds
+    );
+    close_account(cpi_context)?;
+
+    // close legacy game
+    let authority_info = ctx.accounts.game_authority.to_account_info();
+    **authority_info.try_borrow_mut_lamports()? += legacy_info.lamports();
+    **legacy_info.try_borrow_mut_lamports()? = 0;
+    legacy_info.assign(&system_program::ID);
+    legacy_info.realloc(0, false)?;
+
+    msg!("Game migrated: {}", amount);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct MigrateGameContext<'info> {
+    #[account(mut)]
+    pub game_authority: Signer<'info>,
+
+    pub protocol: Account<'info, Protocol>,
+
+    pub price_update: Account<'info, PriceUpdateV2>,
+
+    /// CHECK: legacy game seeded by the feed account, layout and seeds are checked in the instruction
+    #[account(
+        mut,
+        owner = crate::ID @ BullBearProgramError::InvalidLegacyGame,
+    )]
+    pub legacy_game: UncheckedAccount<'info>,
+
+    /// CHECK: current round of the legacy game, checked in the instruction
+    pub legacy_round: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = game_authority,
+        space = 8 + Game::INIT_SPACE,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game_authority.key().as_ref(),
+            protocol.key().as_ref(),
+            mint.key().as_ref(),
+            price_update.price_message.feed_id.as_ref(),
+            ],
+        bump)]
+    pub game: Account<'info, Game>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = legacy_game,
+    )]
+    pub legacy_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = game_authority,
+        associated_token::mint = mint,
+        associated_token::authority = game,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = spl_associated_token_account::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_lang::solana_program::system_program;
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::{read_legacy_account, rewrite_account};
+
+pub fn migrate_round(ctx: Context<MigrateRoundContext>) -> Result<()> {
+
+    let round_info = ctx.accounts.round.to_account_info();
+
+    // check legacy round layout
+    let legacy_round: LegacyRound = read_legacy_account(&round_info, &Round::DISCRIMINATOR, LegacyRound::INIT_SPACE)?;
+    let round_nr = legacy_round.round_nr;
+
+    // check that the round is seeded by its game
+    let (round_key, _) = Pubkey::find_program_address(&[ROUND_SEED.as_bytes(),
+        legacy_round.game.as_ref(),
+        round_nr.to_le_bytes().as_ref()], ctx.program_id);
+    require!(round_key == round_info.key(), BullBearProgramError::InvalidLegacyAccount);
+
+    let round = legacy_round.into_round();
+    rewrite_account(&round_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &round,
+        Round::INIT_SPACE)?;
+
+    msg!("Round migrated: {}", round_nr);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct MigrateRoundContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: round in the legacy layout, layout and seeds are checked in the instruction
+    #[account(
+        mut,
+        owner = crate::ID @ BullBearProgramError::InvalidLegacyAccount,
+    )]
+    pub round: UncheckedAccount<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
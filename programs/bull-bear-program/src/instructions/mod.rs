@@ -32,3 +32,156 @@ pub mod test_price_feed;
 
 pub use update_interval::*;
 pub mod update_interval;
+
+pub use update_feed_account::*;
+pub mod update_feed_account;
+
+pub use migrate_game::*;
+pub mod migrate_game;
+
+pub use update_twap_window::*;
+pub mod update_twap_window;
+
+pub use record_price_sample::*;
+pub mod record_price_sample;
+
+pub use update_price_source::*;
+pub mod update_price_source;
+
+pub use update_flat_band::*;
+pub mod update_flat_band;
+
+pub use update_market::*;
+pub mod update_market;
+
+pub use update_buckets::*;
+pub mod update_buckets;
+
+pub use migrate_round::*;
+pub mod migrate_round;
+
+pub use migrate_bet::*;
+pub mod migrate_bet;
+
+pub use update_head_to_head::*;
+pub mod update_head_to_head;
+
+pub use update_odds::*;
+pub mod update_odds;
+
+pub use initialize_lp_pool::*;
+pub mod initialize_lp_pool;
+
+pub use deposit_liquidity::*;
+pub mod deposit_liquidity;
+
+pub use request_withdrawal::*;
+pub mod request_withdrawal;
+
+pub use process_withdrawal::*;
+pub mod process_withdrawal;
+
+pub use update_seed_amount::*;
+pub mod update_seed_amount;
+
+pub use cancel_bet::*;
+pub mod cancel_bet;
+
+pub use update_cancel_penalty::*;
+pub mod update_cancel_penalty;
+
+pub use switch_bet::*;
+pub mod switch_bet;
+
+pub use update_switch_fee::*;
+pub mod update_switch_fee;
+
+pub use update_tokenized_bets::*;
+pub mod update_tokenized_bets;
+
+pub use exit_bet::*;
+pub mod exit_bet;
+
+pub use update_early_exit::*;
+pub mod update_early_exit;
+
+pub use create_auto_bet::*;
+pub mod create_auto_bet;
+
+pub use cancel_auto_bet::*;
+pub mod cancel_auto_bet;
+
+pub use place_auto_bet::*;
+pub mod place_auto_bet;
+
+pub use initialize_bet_nonce::*;
+pub mod initialize_bet_nonce;
+
+pub use place_signed_bet::*;
+pub mod place_signed_bet;
+
+pub use create_session::*;
+pub mod create_session;
+
+pub use revoke_session::*;
+pub mod revoke_session;
+
+pub use place_session_bet::*;
+pub mod place_session_bet;
+
+pub use claim_session_prize::*;
+pub mod claim_session_prize;
+
+pub use update_allowlist::*;
+pub mod update_allowlist;
+
+pub use update_commit_reveal::*;
+pub mod update_commit_reveal;
+
+pub use commit_bet::*;
+pub mod commit_bet;
+
+pub use reveal_bet::*;
+pub mod reveal_bet;
+
+pub use initialize_player_stats::*;
+pub mod initialize_player_stats;
+
+pub use record_bet_loss::*;
+pub mod record_bet_loss;
+
+pub use update_settlement_fee::*;
+pub mod update_settlement_fee;
+
+pub use create_season::*;
+pub mod create_season;
+
+pub use join_season::*;
+pub mod join_season;
+
+pub use commit_season_ranking::*;
+pub mod commit_season_ranking;
+
+pub use challenge_season_ranking::*;
+pub mod challenge_season_ranking;
+
+pub use claim_season_prize::*;
+pub mod claim_season_prize;
+
+pub use update_referral_share::*;
+pub mod update_referral_share;
+
+pub use bind_referrer::*;
+pub mod bind_referrer;
+
+pub use initialize_referral_rewards::*;
+pub mod initialize_referral_rewards;
+
+pub use claim_referrer_rewards::*;
+pub mod claim_referrer_rewards;
+
+pub use initialize_points::*;
+pub mod initialize_points;
+
+pub use update_points::*;
+pub mod update_points;
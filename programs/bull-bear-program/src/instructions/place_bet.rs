@@ -1,53 +1,60 @@
 
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
-use anchor_spl::token::{spl_token, transfer, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token::{mint_to, spl_token, transfer, Mint, MintTo, Token, TokenAccount, Transfer};
 use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
 
 use crate::errors::BullBearProgramError;
 use crate::states::*;
+use crate::utils::{record_bet, record_bet_points, record_player_wager, record_referrer, record_season_stake};
 
-pub fn place_bet(ctx: Context<PlaceBetContext>, prediction: PriceMovement, amount: u64) -> Result<()> {
+pub fn place_bet(ctx: Context<PlaceBetContext>, outcome: u8, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
 
+    let game = &mut ctx.accounts.game;
     let round = &mut ctx.accounts.round;
     let bet = &mut ctx.accounts.bet; 
 
-    // check if round active
-    require!(round.status == RoundStatus::Active, BullBearProgramError::RoundNotActive);
-    // check if betting open
-    require!(round.betting == BettingStatus::Open, BullBearProgramError::BettingIsClosed);
-
-    match prediction {
-        PriceMovement::Bull => {
-            round.total_up = round.total_up.checked_add(amount).ok_or(BullBearProgramError::MaximumBetAmountReached)?;
-        }
-        PriceMovement::Bear => {
-            round.total_down = round.total_down.checked_add(amount).ok_or(BullBearProgramError::MaximumBetAmountReached)?;
-        }
-        PriceMovement::None => {
-            return Err(BullBearProgramError::InvalidPrediction.into());
-        }
-        PriceMovement::NoChange => {
-            return Err(BullBearProgramError::InvalidPrediction.into());
-        }
-    }
-
-    bet.player = ctx.accounts.player.key();
     bet.round = round.key();
-    bet.prediction = prediction;
-    bet.amount = amount;
-    bet.claimed = false;
     bet.bump = ctx.bumps.bet;
+    record_bet(game, round, bet, ctx.accounts.player.key(), outcome, amount, ctx.accounts.game_vault.amount, &proof)?;
+
+    // bets of referred players earn their referrer a share of the settlement fee
+    record_referrer(&ctx.accounts.referral, bet)?;
+
+    // stats are kept for players that opened them, their settled winning streak raises the loyalty points rate
+    let streak = record_player_wager(&ctx.accounts.player_stats, ctx.accounts.last_bet.as_ref(), ctx.accounts.last_round.as_ref(), bet.key(), round.round_nr, amount)?;
+    record_bet_points(game, bet, streak);
+
+    // stakes placed during a season count against its net winnings
+    record_season_stake(game, &ctx.accounts.season_entry, bet, Clock::get()?.unix_timestamp)?;
 
-    // transfer tokens from player to vault
+    // transfer tokens from funder to vault (the bet belongs to the player either way)
     let cpi_context = CpiContext::new(
         ctx.accounts.token_program.to_account_info(),
-        Transfer{from: ctx.accounts.signer_vault.to_account_info(), to: ctx.accounts.vault.to_account_info(), authority: ctx.accounts.player.to_account_info()}
+        Transfer{from: ctx.accounts.signer_vault.to_account_info(), to: ctx.accounts.vault.to_account_info(), authority: ctx.accounts.funder.to_account_info()}
     );
 
     transfer(cpi_context, amount)?;
 
-    round.num_bets += 1;
+    // tokenized games hand out a receipt that claims the prize
+    if game.tokenized_bets {
+        let receipt_mint = ctx.accounts.receipt_mint.as_ref().ok_or(BullBearProgramError::MissingReceipt)?;
+        let receipt_account = ctx.accounts.receipt_account.as_ref().ok_or(BullBearProgramError::MissingReceipt)?;
+        bet.receipt_mint = receipt_mint.key();
+
+        let game_id = game.key();
+        let round_ref = round.round_nr.to_le_bytes();
+        let bump = round.bump;
+        let signer_seeds: &[&[&[u8]]] = &[&[ROUND_SEED.as_bytes(),
+                game_id.as_ref(),
+                round_ref.as_ref(), &[bump]]];
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo{mint: receipt_mint.to_account_info(), to: receipt_account.to_account_info(), authority: round.to_account_info()},
+            signer_seeds
+        );
+        mint_to(cpi_context, 1)?;
+    }
 
     msg!("Bet placed: {:?}", amount);
     Ok(())
@@ -57,14 +64,18 @@ pub fn place_bet(ctx: Context<PlaceBetContext>, prediction: PriceMovement, amoun
 #[derive(Accounts)]
 pub struct PlaceBetContext<'info> {
     #[account(mut)]
+    pub payer: Signer<'info>,
+    /// beneficiary that owns the bet and receives the winnings, signs to consent
     pub player: Signer<'info>,
+    pub funder: Signer<'info>,
     #[account(
+        mut,
         seeds = [
             GAME_SEED.as_bytes(),
             game.game_authority.key().as_ref(),
             game.protocol.as_ref(),
             game.token.as_ref(),
-            game.feed_account.as_ref()
+            game.feed_id.as_ref()
         ],
         bump = game.bump
     )]
@@ -81,7 +92,7 @@ pub struct PlaceBetContext<'info> {
     pub round: Account<'info, Round>,
     #[account(
         init,
-        payer = player,
+        payer = payer,
         space = 8 + Bet::INIT_SPACE,
         seeds = [
             BET_SEED.as_bytes(),
@@ -90,20 +101,73 @@ pub struct PlaceBetContext<'info> {
             ],
         bump)]
     pub bet: Account<'info, Bet>,
+    /// CHECK: stats of the player, updated once opened
+    #[account(
+        mut,
+        seeds = [
+            PLAYER_STATS_SEED.as_bytes(),
+            player.key().as_ref(),
+            game.key().as_ref()
+            ],
+        bump)]
+    pub player_stats: UncheckedAccount<'info>,
+    pub last_bet: Option<Account<'info, Bet>>,
+    pub last_round: Option<Account<'info, Round>>,
+    /// CHECK: season entry of the player, updated once joined
+    #[account(
+        mut,
+        seeds = [
+            SEASON_ENTRY_SEED.as_bytes(),
+            game.season.as_ref(),
+            player.key().as_ref()
+            ],
+        bump)]
+    pub season_entry: UncheckedAccount<'info>,
+    /// CHECK: referral of the player, read once bound
+    #[account(
+        seeds = [
+            REFERRAL_SEED.as_bytes(),
+            player.key().as_ref()
+            ],
+        bump)]
+    pub referral: UncheckedAccount<'info>,
      #[account(
         constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
     )]
     pub mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = round,
+        seeds = [
+            RECEIPT_SEED.as_bytes(),
+            bet.key().as_ref()
+            ],
+        bump)]
+    pub receipt_mint: Option<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = receipt_mint,
+        associated_token::authority = player,
+    )]
+    pub receipt_account: Option<Account<'info, TokenAccount>>,
     #[account(
         mut,
         associated_token::mint = mint,
         associated_token::authority = round,
     )]
     pub vault: Account<'info, TokenAccount>,
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = game,
+    )]
+    pub game_vault: Account<'info, TokenAccount>,
     #[account(
         mut,
         associated_token::mint = mint,
-        associated_token::authority = player,
+        associated_token::authority = funder,
     )]
     pub signer_vault: Account<'info, TokenAccount>,
     
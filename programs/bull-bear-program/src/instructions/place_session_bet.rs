@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{spl_token, transfer, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::{record_bet, record_bet_points, record_player_wager, record_referrer, record_season_stake};
+
+pub fn place_session_bet(ctx: Context<PlaceSessionBetContext>, outcome: u8, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+
+    let game = &mut ctx.accounts.game;
+    let round = &mut ctx.accounts.round;
+    let session = &mut ctx.accounts.session;
+    let bet = &mut ctx.accounts.bet;
+
+    // check if session active
+    require!(Clock::get()?.unix_timestamp < session.expiry, BullBearProgramError::SessionExpired);
+    // check spend limit
+    let spent = session.spent.checked_add(amount).ok_or(BullBearProgramError::SessionLimitExceeded)?;
+    require!(spent <= session.spend_limit, BullBearProgramError::SessionLimitExceeded);
+    // check that no receipt has to be handed out
+    require!(!game.tokenized_bets, BullBearProgramError::BetIsTokenized);
+
+    session.spent = spent;
+
+    bet.round = round.key();
+    bet.bump = ctx.bumps.bet;
+    record_bet(game, round, bet, session.player, outcome, amount, ctx.accounts.game_vault.amount, &proof)?;
+
+    // bets of referred players earn their referrer a share of the settlement fee
+    record_referrer(&ctx.accounts.referral, bet)?;
+
+    // stats are kept for players that opened them, their settled winning streak raises the loyalty points rate
+    let streak = record_player_wager(&ctx.accounts.player_stats, ctx.accounts.last_bet.as_ref(), ctx.accounts.last_round.as_ref(), bet.key(), round.round_nr, amount)?;
+    record_bet_points(game, bet, streak);
+
+    // stakes placed during a season count against its net winnings
+    record_season_stake(game, &ctx.accounts.season_entry, bet, Clock::get()?.unix_timestamp)?;
+
+    // transfer tokens from player to vault using the session allowance
+    let player = session.player;
+    let session_key = session.session_key;
+    let bump = session.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[SESSION_SEED.as_bytes(),
+            player.as_ref(),
+            session_key.as_ref(), &[bump]]];
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer{from: ctx.accounts.player_vault.to_account_info(), to: ctx.accounts.vault.to_account_info(), authority: session.to_account_info()},
+        signer_seeds
+    );
+    transfer(cpi_context, amount)?;
+
+    msg!("Session bet placed: {:?}", amount);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct PlaceSessionBetContext<'info> {
+    #[account(mut)]
+    pub session_key: Signer<'info>,
+    /// CHECK: main wallet of the session, checked against the session account
+    #[account(address = session.player)]
+    pub player: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            SESSION_SEED.as_bytes(),
+            session.player.as_ref(),
+            session_key.key().as_ref()
+            ],
+        bump = session.bump)]
+    pub session: Account<'info, Session>,
+    #[account(
+        mut,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED.as_bytes(),
+            game.key().as_ref(),
+            game.counter.to_le_bytes().as_ref(),
+        ],
+        bump = round.bump
+    )]
+    pub round: Account<'info, Round>,
+    #[account(
+        init,
+        payer = session_key,
+        space = 8 + Bet::INIT_SPACE,
+        seeds = [
+            BET_SEED.as_bytes(),
+            player.key().as_ref(),
+            round.key().as_ref()
+            ],
+        bump)]
+    pub bet: Account<'info, Bet>,
+    /// CHECK: stats of the player, updated once opened
+    #[account(
+        mut,
+        seeds = [
+            PLAYER_STATS_SEED.as_bytes(),
+            player.key().as_ref(),
+            game.key().as_ref()
+            ],
+        bump)]
+    pub player_stats: UncheckedAccount<'info>,
+    pub last_bet: Option<Account<'info, Bet>>,
+    pub last_round: Option<Account<'info, Round>>,
+    /// CHECK: season entry of the player, updated once joined
+    #[account(
+        mut,
+        seeds = [
+            SEASON_ENTRY_SEED.as_bytes(),
+            game.season.as_ref(),
+            player.key().as_ref()
+            ],
+        bump)]
+    pub season_entry: UncheckedAccount<'info>,
+    /// CHECK: referral of the player, read once bound
+    #[account(
+        seeds = [
+            REFERRAL_SEED.as_bytes(),
+            player.key().as_ref()
+            ],
+        bump)]
+    pub referral: UncheckedAccount<'info>,
+     #[account(
+        constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = round,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = game,
+    )]
+    pub game_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = player,
+    )]
+    pub player_vault: Account<'info, TokenAccount>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = spl_associated_token_account::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{system_program, sysvar};
+use anchor_spl::token::{spl_token, transfer, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::{record_bet, record_bet_points, record_player_wager, record_referrer, record_season_stake, verify_ed25519_signature};
+
+pub fn place_signed_bet(ctx: Context<PlaceSignedBetContext>, intent: BetIntent, proof: Vec<[u8; 32]>) -> Result<()> {
+
+    let game = &mut ctx.accounts.game;
+    let round = &mut ctx.accounts.round;
+    let bet_nonce = &mut ctx.accounts.bet_nonce;
+    let bet = &mut ctx.accounts.bet;
+
+    // check that the intent targets these accounts
+    require!(intent.game == game.key() && intent.round == round.key(), BullBearProgramError::IntentMismatch);
+    // check that the player signed the intent
+    let mut message = Vec::new();
+    intent.serialize(&mut message)?;
+    verify_ed25519_signature(&ctx.accounts.instructions.to_account_info(), &intent.player, &message)?;
+    // check expiry
+    require!(Clock::get()?.unix_timestamp <= intent.expiry, BullBearProgramError::IntentExpired);
+    // check nonce (each intent can be used once)
+    require!(intent.nonce == bet_nonce.nonce, BullBearProgramError::InvalidNonce);
+    // check that no receipt has to be handed out
+    require!(!game.tokenized_bets, BullBearProgramError::BetIsTokenized);
+
+    bet_nonce.nonce += 1;
+
+    bet.round = round.key();
+    bet.bump = ctx.bumps.bet;
+    record_bet(game, round, bet, intent.player, intent.outcome, intent.amount, ctx.accounts.game_vault.amount, &proof)?;
+
+    // bets of referred players earn their referrer a share of the settlement fee
+    record_referrer(&ctx.accounts.referral, bet)?;
+
+    // stats are kept for players that opened them, their settled winning streak raises the loyalty points rate
+    let streak = record_player_wager(&ctx.accounts.player_stats, ctx.accounts.last_bet.as_ref(), ctx.accounts.last_round.as_ref(), bet.key(), round.round_nr, intent.amount)?;
+    record_bet_points(game, bet, streak);
+
+    // stakes placed during a season count against its net winnings
+    record_season_stake(game, &ctx.accounts.season_entry, bet, Clock::get()?.unix_timestamp)?;
+
+    // transfer tokens from player to vault using the delegated allowance
+    let player = intent.player;
+    let bump = bet_nonce.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[BET_NONCE_SEED.as_bytes(),
+            player.as_ref(), &[bump]]];
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer{from: ctx.accounts.player_vault.to_account_info(), to: ctx.accounts.vault.to_account_info(), authority: bet_nonce.to_account_info()},
+        signer_seeds
+    );
+    transfer(cpi_context, intent.amount)?;
+
+    msg!("Signed bet placed: {:?}", intent.amount);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+#[instruction(intent: BetIntent)]
+pub struct PlaceSignedBetContext<'info> {
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+    /// CHECK: player that signed the intent
+    #[account(address = intent.player @ BullBearProgramError::IntentMismatch)]
+    pub player: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED.as_bytes(),
+            game.key().as_ref(),
+            game.counter.to_le_bytes().as_ref(),
+        ],
+        bump = round.bump
+    )]
+    pub round: Account<'info, Round>,
+    #[account(
+        mut,
+        seeds = [
+            BET_NONCE_SEED.as_bytes(),
+            player.key().as_ref()
+            ],
+        bump = bet_nonce.bump)]
+    pub bet_nonce: Account<'info, BetNonce>,
+    #[account(
+        init,
+        payer = relayer,
+        space = 8 + Bet::INIT_SPACE,
+        seeds = [
+            BET_SEED.as_bytes(),
+            player.key().as_ref(),
+            round.key().as_ref()
+            ],
+        bump)]
+    pub bet: Account<'info, Bet>,
+    /// CHECK: stats of the player, updated once opened
+    #[account(
+        mut,
+        seeds = [
+            PLAYER_STATS_SEED.as_bytes(),
+            player.key().as_ref(),
+            game.key().as_ref()
+            ],
+        bump)]
+    pub player_stats: UncheckedAccount<'info>,
+    pub last_bet: Option<Account<'info, Bet>>,
+    pub last_round: Option<Account<'info, Round>>,
+    /// CHECK: season entry of the player, updated once joined
+    #[account(
+        mut,
+        seeds = [
+            SEASON_ENTRY_SEED.as_bytes(),
+            game.season.as_ref(),
+            player.key().as_ref()
+            ],
+        bump)]
+    pub season_entry: UncheckedAccount<'info>,
+    /// CHECK: referral of the player, read once bound
+    #[account(
+        seeds = [
+            REFERRAL_SEED.as_bytes(),
+            player.key().as_ref()
+            ],
+        bump)]
+    pub referral: UncheckedAccount<'info>,
+     #[account(
+        constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = round,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = game,
+    )]
+    pub game_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = player,
+    )]
+    pub player_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: instructions sysvar holding the ed25519 signature check
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = spl_associated_token_account::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
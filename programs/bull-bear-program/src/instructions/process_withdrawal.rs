@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{burn, spl_token, transfer, Burn, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::require_between_rounds;
+
+pub fn process_withdrawal(ctx: Context<ProcessWithdrawalContext>) -> Result<()> {
+
+    let game = &mut ctx.accounts.game;
+    let shares = ctx.accounts.withdrawal.shares;
+
+    // check that no round is running
+    require_between_rounds(&ctx.accounts.round)?;
+
+    // shares are redeemed at the house funds after the last settled round
+    let house_funds = game.house_funds(ctx.accounts.vault.amount);
+    let supply = ctx.accounts.lp_mint.supply;
+    let amount = (shares as u128 * house_funds as u128 / supply as u128) as u64;
+
+    let game_authority = game.game_authority;
+    let game_protocol = game.protocol;
+    let game_token = game.token;
+    let game_feed = game.feed_id;
+    let bump = game.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[GAME_SEED.as_bytes(),
+            game_authority.as_ref(),
+            game_protocol.as_ref(),
+            game_token.as_ref(),
+            game_feed.as_ref(),
+            &[bump]]];
+
+    // burn escrowed shares
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Burn{mint: ctx.accounts.lp_mint.to_account_info(), from: ctx.accounts.lp_escrow.to_account_info(), authority: game.to_account_info()},
+        signer_seeds
+    );
+    burn(cpi_context, shares)?;
+
+    // transfer tokens from game vault to provider
+    if amount > 0 {
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer{from: ctx.accounts.vault.to_account_info(), to: ctx.accounts.provider_vault.to_account_info(), authority: game.to_account_info()},
+            signer_seeds
+        );
+        transfer(cpi_context, amount)?;
+    }
+
+    game.queued_shares -= shares;
+
+    msg!("Withdrawal processed: {} for {} shares", amount, shares);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct ProcessWithdrawalContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: owner of the withdrawal, receives tokens and rent
+    #[account(mut)]
+    pub provider: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref(),
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+
+    /// CHECK: current round of the game, may not be initialized yet
+    #[account(
+        seeds = [
+            ROUND_SEED.as_bytes(),
+            game.key().as_ref(),
+            game.counter.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub round: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = provider,
+        seeds = [
+            WITHDRAWAL_SEED.as_bytes(),
+            provider.key().as_ref(),
+            game.key().as_ref(),
+        ],
+        bump = withdrawal.bump)]
+    pub withdrawal: Account<'info, LpWithdrawal>,
+
+    #[account(
+        constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = game,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = game.lp_mint
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = game,
+    )]
+    pub lp_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = provider,
+    )]
+    pub provider_vault: Account<'info, TokenAccount>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = spl_associated_token_account::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
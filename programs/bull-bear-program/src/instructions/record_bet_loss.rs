@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{spl_token, Mint, Token, TokenAccount};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::{mint_bet_points, settle_player_bet};
+
+pub fn record_bet_loss(ctx: Context<RecordBetLossContext>) -> Result<()> {
+
+    let round = &ctx.accounts.round;
+    let bet = &ctx.accounts.bet;
+
+    // check if round ended
+    require!(round.status == RoundStatus::Ended, BullBearProgramError::CurrentRoundNotEnded);
+    // check if position still belongs to the player
+    require!(bet.status == BetStatus::Active, BullBearProgramError::BetExited);
+    // check that the bet lost (unrevealed bets are forfeited)
+    require!(bet.outcome == NO_OUTCOME
+        || (!round.is_refund() && round.winning_outcome != bet.outcome), BullBearProgramError::BetNotLost);
+
+    // the loss is recorded while it is the pending bet of the player stats (the bet account is closed)
+    settle_player_bet(&ctx.accounts.player_stats, bet.key(), round, bet)?;
+
+    // loyalty points of the stake are minted once the bet settled
+    mint_bet_points(&ctx.accounts.game, round, bet, ctx.accounts.points_mint.as_ref(), ctx.accounts.points_account.as_ref(), &ctx.accounts.token_program)?;
+
+    msg!("Loss recorded for round {}", round.round_nr);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct RecordBetLossContext<'info> {
+    pub signer: Signer<'info>,
+    /// CHECK: owner of the bet, receives the bet rent
+    #[account(mut, address = bet.player)]
+    pub player: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        seeds = [
+            ROUND_SEED.as_bytes(),
+            game.key().as_ref(),
+            round.round_nr.to_le_bytes().as_ref(),
+        ],
+        bump = round.bump
+    )]
+    pub round: Account<'info, Round>,
+    #[account(
+        mut,
+        close = player,
+        seeds = [
+            BET_SEED.as_bytes(),
+            bet.player.as_ref(),
+            round.key().as_ref()
+            ],
+        bump = bet.bump)]
+    pub bet: Account<'info, Bet>,
+    /// CHECK: stats of the bet owner, updated once opened
+    #[account(
+        mut,
+        seeds = [
+            PLAYER_STATS_SEED.as_bytes(),
+            bet.player.as_ref(),
+            game.key().as_ref()
+            ],
+        bump)]
+    pub player_stats: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = game.points_mint
+    )]
+    pub points_mint: Option<Account<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = points_mint,
+        associated_token::authority = player,
+    )]
+    pub points_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
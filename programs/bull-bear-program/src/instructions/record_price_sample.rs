@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use pyth_solana_receiver_sdk::price_update::{PriceUpdateV2, VerificationLevel};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::get_game_price;
+
+pub fn record_price_sample(ctx: Context<RecordPriceSampleContext>) -> Result<()> {
+
+    let game = &ctx.accounts.game;
+    let round = &mut ctx.accounts.round;
+
+    // check if twap settlement is enabled
+    require!(game.twap_window > 0, BullBearProgramError::TwapNotEnabled);
+    // check if round active
+    require!(round.status == RoundStatus::Active, BullBearProgramError::RoundNotActive);
+
+    // samples are taken during the first and the last seconds of the round
+    let clock = Clock::get()?;
+    let window = game.twap_window as i64;
+    let (samples, verification_level) = if clock.unix_timestamp <= round.start_time + window {
+        (&mut round.start_samples, VerificationLevel::Partial{num_signatures: 1})
+    } else if clock.unix_timestamp >= round.end_time - window {
+        // end samples settle the round, same as the spot price in end_round
+        (&mut round.end_samples, VerificationLevel::Full)
+    } else {
+        return Err(BullBearProgramError::SamplingWindowClosed.into());
+    };
+
+    let sample = get_game_price(&ctx.accounts.price_update, &game.feed_id, game,
+        MAXIMUM_AGE,
+        verification_level)?;
+
+    // check if price is newer than the last sample
+    require!(samples.last().map_or(true, |last| last.publish_time < sample.publish_time), BullBearProgramError::PriceSampleAlreadyRecorded);
+
+    samples.push(sample);
+
+    msg!("Price sample recorded: {}", sample.price);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct RecordPriceSampleContext<'info> {
+    #[account(
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED.as_bytes(),
+            game.key().as_ref(),
+            game.counter.to_le_bytes().as_ref(),
+        ],
+        bump = round.bump
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(address = game.feed_account)]
+    pub price_update: Account<'info, PriceUpdateV2>,
+}
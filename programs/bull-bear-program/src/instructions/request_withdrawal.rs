@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{spl_token, transfer, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+
+pub fn request_withdrawal(ctx: Context<RequestWithdrawalContext>, shares: u64) -> Result<()> {
+
+    let game = &mut ctx.accounts.game;
+    let withdrawal = &mut ctx.accounts.withdrawal;
+
+    // check share amount
+    require!(shares > 0, BullBearProgramError::InvalidLiquidityAmount);
+
+    // shares are held in escrow until the withdrawal is processed between rounds
+    let cpi_context = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer{from: ctx.accounts.provider_lp_account.to_account_info(), to: ctx.accounts.lp_escrow.to_account_info(), authority: ctx.accounts.provider.to_account_info()}
+    );
+    transfer(cpi_context, shares)?;
+
+    withdrawal.provider = ctx.accounts.provider.key();
+    withdrawal.game = game.key();
+    withdrawal.shares = shares;
+    withdrawal.bump = ctx.bumps.withdrawal;
+
+    game.queued_shares += shares;
+
+    msg!("Withdrawal requested: {}", shares);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct RequestWithdrawalContext<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref(),
+        ],
+        bump = game.bump,
+        constraint = game.lp_mint != Pubkey::default() @ BullBearProgramError::LiquidityPoolNotInitialized
+    )]
+    pub game: Account<'info, Game>,
+
+    #[account(
+        init,
+        payer = provider,
+        space = 8 + LpWithdrawal::INIT_SPACE,
+        seeds = [
+            WITHDRAWAL_SEED.as_bytes(),
+            provider.key().as_ref(),
+            game.key().as_ref(),
+        ],
+        bump)]
+    pub withdrawal: Account<'info, LpWithdrawal>,
+
+    #[account(address = game.lp_mint)]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = provider,
+    )]
+    pub provider_lp_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = game,
+    )]
+    pub lp_escrow: Account<'info, TokenAccount>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = spl_associated_token_account::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::bet_commitment;
+
+pub fn reveal_bet(ctx: Context<RevealBetContext>, outcome: u8, salt: [u8; 32]) -> Result<()> {
+
+    let game = &ctx.accounts.game;
+    let round = &mut ctx.accounts.round;
+    let bet = &mut ctx.accounts.bet;
+
+    // check if already revealed
+    require!(bet.outcome == NO_OUTCOME, BullBearProgramError::BetAlreadyRevealed);
+    // check if round active
+    require!(round.status == RoundStatus::Active, BullBearProgramError::RoundNotActive);
+    // check if reveal phase open (after betting closed, until the reveal deadline)
+    let clock = Clock::get()?;
+    require!(round.betting == BettingStatus::Closed
+        && clock.unix_timestamp <= game.reveal_deadline(round.start_time), BullBearProgramError::RevealPhaseNotOpen);
+
+    // check if outcome exists in the game market
+    require!(outcome < game.num_outcomes(), BullBearProgramError::InvalidPrediction);
+    // check that the side matches the commitment
+    require!(bet_commitment(&bet.player, outcome, &salt) == bet.commitment, BullBearProgramError::InvalidReveal);
+
+    // stake moves to the pool of the revealed side
+    round.committed -= bet.amount;
+    round.totals[outcome as usize] += bet.amount;
+    bet.outcome = outcome;
+
+    msg!("Bet revealed: {}", outcome);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct RevealBetContext<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED.as_bytes(),
+            game.key().as_ref(),
+            game.counter.to_le_bytes().as_ref(),
+        ],
+        bump = round.bump
+    )]
+    pub round: Account<'info, Round>,
+    #[account(
+        mut,
+        seeds = [
+            BET_SEED.as_bytes(),
+            player.key().as_ref(),
+            round.key().as_ref()
+            ],
+        bump = bet.bump)]
+    pub bet: Account<'info, Bet>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{revoke, spl_token, Mint, Revoke, Token, TokenAccount};
+
+use crate::states::*;
+use crate::utils::is_delegate;
+
+pub fn revoke_session(ctx: Context<RevokeSessionContext>) -> Result<()> {
+
+    // remove the remaining allowance of the session (the token account may delegate to another account by now)
+    if is_delegate(&ctx.accounts.signer_vault, &ctx.accounts.session.key()) {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Revoke{source: ctx.accounts.signer_vault.to_account_info(), authority: ctx.accounts.player.to_account_info()}
+        );
+        revoke(cpi_context)?;
+    }
+
+    msg!("Session revoked: {}", ctx.accounts.session.session_key);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct RevokeSessionContext<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        mut,
+        close = player,
+        seeds = [
+            SESSION_SEED.as_bytes(),
+            player.key().as_ref(),
+            session.session_key.as_ref()
+            ],
+        bump = session.bump)]
+    pub session: Account<'info, Session>,
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = player,
+    )]
+    pub signer_vault: Account<'info, TokenAccount>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
@@ -2,10 +2,13 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_program;
 use pyth_solana_receiver_sdk::price_update::{PriceUpdateV2, VerificationLevel};
+use anchor_spl::token::{spl_token, transfer, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
 use core::cmp::max;
 
 use crate::errors::BullBearProgramError;
 use crate::states::*;
+use crate::utils::get_game_price;
 
 
 pub fn start_round(ctx: Context<StartRoundContext>) -> Result<()> {
@@ -23,15 +26,55 @@ pub fn start_round(ctx: Context<StartRoundContext>) -> Result<()> {
     round.start_time = clock.unix_timestamp;
     round.end_time = clock.unix_timestamp + i64::from_ne_bytes(game.round_interval.to_ne_bytes());
 
-    let price_update = &mut ctx.accounts.price_update;
-    let price = price_update.get_price_no_older_than_with_custom_verification_level(&Clock::get()?,
+    let price = get_game_price(&ctx.accounts.price_update, &game.feed_id, game,
         max(MAXIMUM_AGE, game.round_interval),
-        &game.feed_id,VerificationLevel::Partial{num_signatures: 1})?;
+        VerificationLevel::Partial{num_signatures: 1})?;
    
     let sol_price = price.price;
 
     round.start_price = sol_price;
+
+    // head-to-head games also record the price of the second feed
+    if game.market == MarketType::HeadToHead {
+        let price_update_b = ctx.accounts.price_update_b.as_ref().ok_or(BullBearProgramError::MissingSecondFeed)?;
+        let price_b = get_game_price(price_update_b, &game.feed_id_b, game,
+            max(MAXIMUM_AGE, game.round_interval),
+            VerificationLevel::Partial{num_signatures: 1})?;
+        round.start_price_b = price_b.price;
+    }
+
+    // opening price is the first start sample
+    if game.twap_window > 0 {
+        round.start_samples.push(PriceSample{price: sol_price, publish_time: price.publish_time});
+    }
     
+    // house seeds every outcome of parimutuel pools so winners always have a counterparty
+    if game.seed_amount > 0 && !game.fixed_odds {
+        let num_outcomes = game.num_outcomes() as usize;
+        let seed_total = game.seed_amount.checked_mul(num_outcomes as u64).ok_or(BullBearProgramError::InsufficientHouseFunds)?;
+        require!(game.house_funds(ctx.accounts.game_vault.amount) >= seed_total, BullBearProgramError::InsufficientHouseFunds);
+
+        let game_authority = game.game_authority;
+        let game_protocol = game.protocol;
+        let game_token = game.token;
+        let game_feed = game.feed_id;
+        let bump = game.bump;
+        let signer_seeds: &[&[&[u8]]] = &[&[GAME_SEED.as_bytes(),
+                game_authority.as_ref(),
+                game_protocol.as_ref(),
+                game_token.as_ref(),
+                game_feed.as_ref(),
+                &[bump]]];
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer{from: ctx.accounts.game_vault.to_account_info(), to: ctx.accounts.round_vault.to_account_info(), authority: game.to_account_info()},
+            signer_seeds
+        );
+        transfer(cpi_context, seed_total)?;
+
+        round.seeds[..num_outcomes].fill(game.seed_amount);
+    }
+
     round.status = RoundStatus::Active;
     round.betting = BettingStatus::Open;
     round.result = PriceMovement::None;
@@ -52,7 +95,7 @@ pub struct StartRoundContext<'info> {
             game_authority.key().as_ref(),
             game.protocol.as_ref(),
             game.token.as_ref(),
-            game.feed_account.as_ref()
+            game.feed_id.as_ref()
         ],
         bump = game.bump
     )]
@@ -71,6 +114,34 @@ pub struct StartRoundContext<'info> {
     #[account(address = game.feed_account)]
     pub price_update: Account<'info, PriceUpdateV2>,
 
+    #[account(address = game.feed_account_b)]
+    pub price_update_b: Option<Account<'info, PriceUpdateV2>>,
+
+    #[account(
+        constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = round,
+    )]
+    pub round_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = game,
+    )]
+    pub game_vault: Account<'info, TokenAccount>,
+
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = spl_associated_token_account::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
      #[account(address = system_program::ID)]
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::{spl_token, transfer, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::{spl_associated_token_account, AssociatedToken};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::update_exposure;
+
+pub fn switch_bet(ctx: Context<SwitchBetContext>, outcome: u8) -> Result<()> {
+
+    let game = &mut ctx.accounts.game;
+    let round = &mut ctx.accounts.round;
+    let bet = &mut ctx.accounts.bet;
+
+    // check if authorized
+    require!(bet.player == *ctx.accounts.player.key, BullBearProgramError::SignerNotAuthorized);
+    // check that the position was not handed out as a receipt
+    require!(!bet.is_tokenized(), BullBearProgramError::BetIsTokenized);
+    // check that the side of a committed bet stays hidden
+    require!(bet.outcome != NO_OUTCOME, BullBearProgramError::BetNotRevealed);
+    // check if round active
+    require!(round.status == RoundStatus::Active, BullBearProgramError::RoundNotActive);
+    // check if betting open
+    require!(round.betting == BettingStatus::Open, BullBearProgramError::BettingIsClosed);
+
+    // check if outcome exists in the game market and differs from the current one
+    require!(outcome < game.num_outcomes() && outcome != bet.outcome, BullBearProgramError::InvalidPrediction);
+
+    // fee is taken from the stake
+    let fee = (bet.amount as u128 * game.switch_fee_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+
+    // move stake between the pools
+    round.totals[bet.outcome as usize] -= bet.amount;
+    if bet.odds_bps > 0 {
+        round.payouts[bet.outcome as usize] -= bet.fixed_payout();
+    }
+
+    bet.outcome = outcome;
+    bet.amount -= fee;
+    round.totals[outcome as usize] += bet.amount;
+
+    // fixed-odds bets are quoted again for the new outcome
+    if bet.odds_bps > 0 {
+        let odds_bps = game.odds_bps[outcome as usize];
+        require!(odds_bps > 0, BullBearProgramError::OddsNotQuoted);
+        bet.odds_bps = odds_bps;
+        round.payouts[outcome as usize] += bet.fixed_payout();
+
+        update_exposure(game, round, ctx.accounts.game_vault.amount)?;
+    }
+
+    // transfer fee to game vault
+    if fee > 0 {
+        let game_id = game.key();
+        let round_ref = round.round_nr.to_le_bytes();
+        let bump = round.bump;
+        let signer_seeds: &[&[&[u8]]] = &[&[ROUND_SEED.as_bytes(),
+                game_id.as_ref(),
+                round_ref.as_ref(), &[bump]]];
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer{from: ctx.accounts.vault.to_account_info(), to: ctx.accounts.game_vault.to_account_info(), authority: round.to_account_info()},
+            signer_seeds
+        );
+        transfer(cpi_context, fee)?;
+    }
+
+    msg!("Bet switched to {} (fee {})", outcome, fee);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct SwitchBetContext<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game.game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref()
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [
+            ROUND_SEED.as_bytes(),
+            game.key().as_ref(),
+            game.counter.to_le_bytes().as_ref(),
+        ],
+        bump = round.bump
+    )]
+    pub round: Account<'info, Round>,
+    #[account(
+        mut,
+        seeds = [
+            BET_SEED.as_bytes(),
+            player.key().as_ref(),
+            round.key().as_ref()
+            ],
+        bump = bet.bump)]
+    pub bet: Account<'info, Bet>,
+     #[account(
+        constraint = mint.key() == game.token @ BullBearProgramError::InvalidMintAccount
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = round,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = game,
+    )]
+    pub game_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = spl_token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(address = spl_associated_token_account::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
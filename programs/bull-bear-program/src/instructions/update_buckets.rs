@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::require_between_rounds;
+
+pub fn update_buckets(ctx: Context<UpdateBucketsContext>, bucket_bounds: Vec<i32>) -> Result<()> {
+
+    let game = &mut ctx.accounts.game;
+
+    // check game authority
+    require!(game.game_authority == *ctx.accounts.game_authority.key, BullBearProgramError::SignerNotAuthorized);
+
+    // check that no round is running
+    require_between_rounds(&ctx.accounts.round)?;
+
+    // check that bounds (price change in bps) are increasing and fit the outcomes
+    require!(!bucket_bounds.is_empty() && bucket_bounds.len() < MAX_OUTCOMES, BullBearProgramError::InvalidBuckets);
+    require!(bucket_bounds.windows(2).all(|pair| pair[0] < pair[1]), BullBearProgramError::InvalidBuckets);
+
+    game.bucket_bounds = [0; MAX_OUTCOMES - 1];
+    game.bucket_bounds[..bucket_bounds.len()].copy_from_slice(&bucket_bounds);
+    game.num_buckets = bucket_bounds.len() as u8 + 1;
+    game.market = MarketType::Buckets;
+
+    msg!("Buckets updated.");
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct UpdateBucketsContext<'info> {
+    #[account(mut)]
+    pub game_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref(),
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+
+    /// CHECK: current round of the game, may not be initialized yet
+    #[account(
+        seeds = [
+            ROUND_SEED.as_bytes(),
+            game.key().as_ref(),
+            game.counter.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub round: UncheckedAccount<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
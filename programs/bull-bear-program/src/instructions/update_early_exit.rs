@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::require_between_rounds;
+
+pub fn update_early_exit(ctx: Context<UpdateEarlyExitContext>, early_exit: bool, exit_spread_bps: u16) -> Result<()> {
+
+    let game = &mut ctx.accounts.game;
+
+    // check game authority
+    require!(game.game_authority == *ctx.accounts.game_authority.key, BullBearProgramError::SignerNotAuthorized);
+
+    // check that no round is running
+    require_between_rounds(&ctx.accounts.round)?;
+
+    // check that the spread is below 100%
+    require!((exit_spread_bps as u64) < BPS_DENOMINATOR, BullBearProgramError::InvalidExitSpread);
+
+    game.early_exit = early_exit;
+    game.exit_spread_bps = exit_spread_bps;
+
+    msg!("Early exit updated.");
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct UpdateEarlyExitContext<'info> {
+    #[account(mut)]
+    pub game_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref(),
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+
+    /// CHECK: current round of the game, may not be initialized yet
+    #[account(
+        seeds = [
+            ROUND_SEED.as_bytes(),
+            game.key().as_ref(),
+            game.counter.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub round: UncheckedAccount<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
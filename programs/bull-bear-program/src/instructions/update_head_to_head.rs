@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, PriceUpdateV2};
+use core::cmp::max;
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::{require_between_rounds, validate_price_feed};
+
+pub fn update_head_to_head(ctx: Context<UpdateHeadToHeadContext>, feed_id_b: String) -> Result<()> {
+
+    let game = &mut ctx.accounts.game;
+
+    // check game authority
+    require!(game.game_authority == *ctx.accounts.game_authority.key, BullBearProgramError::SignerNotAuthorized);
+
+    // check that no round is running
+    require_between_rounds(&ctx.accounts.round)?;
+
+    // check that rounds settle on spot prices (only the first feed is sampled)
+    require!(game.twap_window == 0, BullBearProgramError::TwapNotSupported);
+
+    // check that the second price account carries the requested feed with a fresh price
+    let feed_id_b = get_feed_id_from_hex(&feed_id_b)?;
+    validate_price_feed(&ctx.accounts.price_update_b, &feed_id_b, max(MAXIMUM_AGE, game.round_interval))?;
+
+    game.feed_id_b = feed_id_b;
+    game.feed_account_b = ctx.accounts.price_update_b.key();
+    game.market = MarketType::HeadToHead;
+
+    msg!("Head-to-head feed updated.");
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct UpdateHeadToHeadContext<'info> {
+    #[account(mut)]
+    pub game_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref(),
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+
+    /// CHECK: current round of the game, may not be initialized yet
+    #[account(
+        seeds = [
+            ROUND_SEED.as_bytes(),
+            game.key().as_ref(),
+            game.counter.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub round: UncheckedAccount<'info>,
+
+    pub price_update_b: Account<'info, PriceUpdateV2>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
@@ -13,6 +13,11 @@ pub fn update_interval(ctx: Context<UpdateIntervalContext>, interval: u64) -> Re
 
     // might need some additional check like only when current round not running?
 
+    // check that the twap windows still fit into the round
+    require!(game.twap_window <= interval / 2, BullBearProgramError::InvalidTwapWindow);
+    // check that the reveal phase still fits into the round
+    require!(!game.commit_reveal || game.reveal_window <= interval / 2, BullBearProgramError::InvalidRevealWindow);
+
     game.round_interval = interval;
 
     msg!("Interval updated.");
@@ -32,7 +37,7 @@ pub struct UpdateIntervalContext<'info> {
             game_authority.key().as_ref(),
             game.protocol.as_ref(),
             game.token.as_ref(),
-            game.feed_account.as_ref(),
+            game.feed_id.as_ref(),
             ],
         bump)]
     pub game: Account<'info, Game>,
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::require_between_rounds;
+
+pub fn update_market(ctx: Context<UpdateMarketContext>, market: MarketType) -> Result<()> {
+
+    let game = &mut ctx.accounts.game;
+
+    // check game authority
+    require!(game.game_authority == *ctx.accounts.game_authority.key, BullBearProgramError::SignerNotAuthorized);
+
+    // check that no round is running
+    require_between_rounds(&ctx.accounts.round)?;
+
+    // check that buckets are defined
+    require!(market != MarketType::Buckets || game.num_buckets > 1, BullBearProgramError::InvalidBuckets);
+
+    // check that the second feed is defined
+    require!(market != MarketType::HeadToHead || game.feed_account_b != Pubkey::default(), BullBearProgramError::MissingSecondFeed);
+    // check that head-to-head rounds settle on spot prices
+    require!(market != MarketType::HeadToHead || game.twap_window == 0, BullBearProgramError::TwapNotSupported);
+
+    game.market = market;
+
+    msg!("Market updated.");
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct UpdateMarketContext<'info> {
+    #[account(mut)]
+    pub game_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref(),
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+
+    /// CHECK: current round of the game, may not be initialized yet
+    #[account(
+        seeds = [
+            ROUND_SEED.as_bytes(),
+            game.key().as_ref(),
+            game.counter.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub round: UncheckedAccount<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+use crate::utils::require_between_rounds;
+
+pub fn update_odds(ctx: Context<UpdateOddsContext>, odds_bps: Vec<u32>, max_exposure_bps: u16) -> Result<()> {
+
+    let game = &mut ctx.accounts.game;
+
+    // check game authority
+    require!(game.game_authority == *ctx.accounts.game_authority.key, BullBearProgramError::SignerNotAuthorized);
+
+    // check that no round is running
+    require_between_rounds(&ctx.accounts.round)?;
+
+    // empty odds switch back to parimutuel pools
+    if odds_bps.is_empty() {
+        game.fixed_odds = false;
+        game.odds_bps = [0; MAX_OUTCOMES];
+        game.max_exposure_bps = 0;
+
+        msg!("Odds updated: parimutuel");
+        return Ok(());
+    }
+
+    // check that sides are known when quoting
+    require!(!game.commit_reveal, BullBearProgramError::CommitRevealNotSupported);
+
+    // check that every outcome of the market is quoted and pays more than the stake
+    require!(odds_bps.len() == game.num_outcomes() as usize, BullBearProgramError::InvalidOdds);
+    require!(odds_bps.iter().all(|odds| *odds as u64 > BPS_DENOMINATOR), BullBearProgramError::InvalidOdds);
+
+    // check that the exposure limit is a share of the game vault
+    require!(max_exposure_bps > 0 && max_exposure_bps as u64 <= BPS_DENOMINATOR, BullBearProgramError::InvalidExposureLimit);
+
+    let mut quoted = [0; MAX_OUTCOMES];
+    quoted[..odds_bps.len()].copy_from_slice(&odds_bps);
+
+    game.fixed_odds = true;
+    game.odds_bps = quoted;
+    game.max_exposure_bps = max_exposure_bps;
+
+    msg!("Odds updated: {:?}", odds_bps);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct UpdateOddsContext<'info> {
+    #[account(mut)]
+    pub game_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref(),
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+
+    /// CHECK: current round of the game, may not be initialized yet
+    #[account(
+        seeds = [
+            ROUND_SEED.as_bytes(),
+            game.key().as_ref(),
+            game.counter.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub round: UncheckedAccount<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+
+pub fn update_points(ctx: Context<UpdatePointsContext>, points_enabled: bool, points_rate_bps: u32, streak_bonus_bps: u16) -> Result<()> {
+
+    let game = &mut ctx.accounts.game;
+
+    // check game authority
+    require!(game.game_authority == *ctx.accounts.game_authority.key, BullBearProgramError::SignerNotAuthorized);
+
+    if points_enabled {
+        // check that the points mint exists
+        require!(game.points_mint != Pubkey::default(), BullBearProgramError::PointsNotInitialized);
+        // check that bets earn points
+        require!(points_rate_bps > 0, BullBearProgramError::InvalidPointsRate);
+    }
+
+    game.points_enabled = points_enabled;
+    game.points_rate_bps = points_rate_bps;
+    game.streak_bonus_bps = streak_bonus_bps;
+
+    msg!("Loyalty points updated: {} {} {}", points_enabled, points_rate_bps, streak_bonus_bps);
+    Ok(())
+}
+
+
+#[derive(Accounts)]
+pub struct UpdatePointsContext<'info> {
+    #[account(mut)]
+    pub game_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            GAME_SEED.as_bytes(),
+            game_authority.key().as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref(),
+        ],
+        bump = game.bump
+    )]
+    pub game: Account<'info, Game>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
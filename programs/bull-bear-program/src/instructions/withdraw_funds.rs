@@ -10,10 +10,13 @@ use crate::states::*;
 pub fn withdraw_funds(ctx: Context<WithdrawFundsContext>) -> Result<()> {
 
     let game = &mut ctx.accounts.game;
-    let token_balance = ctx.accounts.vault.amount;
+    // funds rolled over to the next round and funds backing open fixed-odds bets stay in the vault
+    let token_balance = game.house_funds(ctx.accounts.vault.amount);
 
     // check if authorized
     require!(game.game_authority == *ctx.accounts.game_authority.key, BullBearProgramError::SignerNotAuthorized);
+    // check that house funds are not owned by liquidity providers
+    require!(game.lp_mint == Pubkey::default(), BullBearProgramError::LiquidityPoolEnabled);
 
     // check if funds available
     require!(token_balance > 0, BullBearProgramError::NothingToWithdraw);
@@ -22,7 +25,7 @@ pub fn withdraw_funds(ctx: Context<WithdrawFundsContext>) -> Result<()> {
     let game_authority =  *ctx.accounts.game_authority.key;
     let game_protocol = game.protocol;
     let game_token = game.token;
-    let game_feed = game.feed_account;
+    let game_feed = game.feed_id;
     let bump = game.bump;
     let signer_seeds: &[&[&[u8]]] = &[&[GAME_SEED.as_bytes(),
             game_authority.as_ref(),
@@ -54,7 +57,7 @@ pub struct WithdrawFundsContext<'info> {
             game_authority.key().as_ref(),
             game.protocol.as_ref(),
             game.token.as_ref(),
-            game.feed_account.as_ref()
+            game.feed_id.as_ref()
         ],
         bump = game.bump
     )]
@@ -4,6 +4,8 @@ use crate::instructions::*;
 pub mod errors;
 pub mod instructions;
 pub mod states;
+pub mod utils;
+pub mod pricing;
 
 declare_id!("FKkP7JrUxzVYgZfgvb1J86SNuFmPAEtCURD6snMtcjPu");
 
@@ -27,10 +29,106 @@ pub mod bull_bear_program {
         return start_round(ctx);
     }
 
-    pub fn place_new_bet(ctx: Context<PlaceBetContext>, prediction: states::PriceMovement, amount: u64) -> Result<()> {
-        return place_bet(ctx, prediction, amount);
+    pub fn place_new_bet(ctx: Context<PlaceBetContext>, outcome: u8, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+        return place_bet(ctx, outcome, amount, proof);
     }
     
+    pub fn cancel_placed_bet(ctx: Context<CancelBetContext>) -> Result<()> {
+        return cancel_bet(ctx);
+    }
+
+    pub fn switch_placed_bet(ctx: Context<SwitchBetContext>, outcome: u8) -> Result<()> {
+        return switch_bet(ctx, outcome);
+    }
+
+    pub fn exit_placed_bet(ctx: Context<ExitBetContext>) -> Result<()> {
+        return exit_bet(ctx);
+    }
+
+    pub fn commit_new_bet(ctx: Context<CommitBetContext>, commitment: [u8; 32], amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+        return commit_bet(ctx, commitment, amount, proof);
+    }
+
+    pub fn reveal_committed_bet(ctx: Context<RevealBetContext>, outcome: u8, salt: [u8; 32]) -> Result<()> {
+        return reveal_bet(ctx, outcome, salt);
+    }
+
+    pub fn create_game_auto_bet(ctx: Context<CreateAutoBetContext>, outcome: u8, amount: u64, rounds: u16) -> Result<()> {
+        return create_auto_bet(ctx, outcome, amount, rounds);
+    }
+
+    pub fn cancel_game_auto_bet(ctx: Context<CancelAutoBetContext>) -> Result<()> {
+        return cancel_auto_bet(ctx);
+    }
+
+    pub fn execute_auto_bet(ctx: Context<PlaceAutoBetContext>, proof: Vec<[u8; 32]>) -> Result<()> {
+        return place_auto_bet(ctx, proof);
+    }
+
+    pub fn initialize_player_nonce(ctx: Context<InitializeBetNonceContext>) -> Result<()> {
+        return initialize_bet_nonce(ctx);
+    }
+
+    pub fn place_bet_signed(ctx: Context<PlaceSignedBetContext>, intent: states::BetIntent, proof: Vec<[u8; 32]>) -> Result<()> {
+        return place_signed_bet(ctx, intent, proof);
+    }
+
+    pub fn create_player_session(ctx: Context<CreateSessionContext>, session_key: Pubkey, expiry: i64, spend_limit: u64) -> Result<()> {
+        return create_session(ctx, session_key, expiry, spend_limit);
+    }
+
+    pub fn revoke_player_session(ctx: Context<RevokeSessionContext>) -> Result<()> {
+        return revoke_session(ctx);
+    }
+
+    pub fn place_new_bet_with_session(ctx: Context<PlaceSessionBetContext>, outcome: u8, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+        return place_session_bet(ctx, outcome, amount, proof);
+    }
+
+    pub fn claim_prize_with_session(ctx: Context<ClaimSessionPrizeContext>) -> Result<()> {
+        return claim_session_prize(ctx);
+    }
+
+    pub fn initialize_game_player_stats(ctx: Context<InitializePlayerStatsContext>) -> Result<()> {
+        return initialize_player_stats(ctx);
+    }
+
+    pub fn record_lost_bet(ctx: Context<RecordBetLossContext>) -> Result<()> {
+        return record_bet_loss(ctx);
+    }
+
+    pub fn create_game_season(ctx: Context<CreateSeasonContext>, season_nr: u16, start_time: i64, end_time: i64, fee_share_bps: u16, payout_bps: Vec<u16>, challenge_period: u64) -> Result<()> {
+        return create_season(ctx, season_nr, start_time, end_time, fee_share_bps, payout_bps, challenge_period);
+    }
+
+    pub fn join_game_season(ctx: Context<JoinSeasonContext>) -> Result<()> {
+        return join_season(ctx);
+    }
+
+    pub fn commit_ranking(ctx: Context<CommitSeasonRankingContext>) -> Result<()> {
+        return commit_season_ranking(ctx);
+    }
+
+    pub fn challenge_ranking(ctx: Context<ChallengeSeasonRankingContext>) -> Result<()> {
+        return challenge_season_ranking(ctx);
+    }
+
+    pub fn claim_season_reward(ctx: Context<ClaimSeasonPrizeContext>) -> Result<()> {
+        return claim_season_prize(ctx);
+    }
+
+    pub fn bind_player_referrer(ctx: Context<BindReferrerContext>, referrer: Pubkey) -> Result<()> {
+        return bind_referrer(ctx, referrer);
+    }
+
+    pub fn initialize_referrer_rewards(ctx: Context<InitializeReferralRewardsContext>) -> Result<()> {
+        return initialize_referral_rewards(ctx);
+    }
+
+    pub fn claim_referral_rewards(ctx: Context<ClaimReferrerRewardsContext>) -> Result<()> {
+        return claim_referrer_rewards(ctx);
+    }
+
     pub fn close_betting_phase(ctx: Context<CloseBettingContext>) -> Result<()> {
         return close_betting(ctx);
     }
@@ -51,6 +149,114 @@ pub mod bull_bear_program {
         return update_interval(ctx, round_interval);
     }
     
+    pub fn update_game_feed_account(ctx: Context<UpdateFeedAccountContext>) -> Result<()> {
+        return update_feed_account(ctx);
+    }
+
+    pub fn migrate_legacy_game(ctx: Context<MigrateGameContext>) -> Result<()> {
+        return migrate_game(ctx);
+    }
+
+    pub fn migrate_legacy_round(ctx: Context<MigrateRoundContext>) -> Result<()> {
+        return migrate_round(ctx);
+    }
+
+    pub fn migrate_legacy_bet(ctx: Context<MigrateBetContext>) -> Result<()> {
+        return migrate_bet(ctx);
+    }
+
+    pub fn update_game_twap_window(ctx: Context<UpdateTwapWindowContext>, twap_window: u64) -> Result<()> {
+        return update_twap_window(ctx, twap_window);
+    }
+
+    pub fn record_round_price_sample(ctx: Context<RecordPriceSampleContext>) -> Result<()> {
+        return record_price_sample(ctx);
+    }
+
+    pub fn update_game_price_source(ctx: Context<UpdatePriceSourceContext>, price_source: states::PriceSource, max_ema_deviation_bps: u16) -> Result<()> {
+        return update_price_source(ctx, price_source, max_ema_deviation_bps);
+    }
+
+    pub fn update_game_flat_band(ctx: Context<UpdateFlatBandContext>, flat_band_bps: u16, flat_policy: states::FlatPolicy) -> Result<()> {
+        return update_flat_band(ctx, flat_band_bps, flat_policy);
+    }
+
+    pub fn update_game_market(ctx: Context<UpdateMarketContext>, market: states::MarketType) -> Result<()> {
+        return update_market(ctx, market);
+    }
+
+    pub fn update_game_buckets(ctx: Context<UpdateBucketsContext>, bucket_bounds: Vec<i32>) -> Result<()> {
+        return update_buckets(ctx, bucket_bounds);
+    }
+
+    pub fn update_game_head_to_head(ctx: Context<UpdateHeadToHeadContext>, feed_id_b: String) -> Result<()> {
+        return update_head_to_head(ctx, feed_id_b);
+    }
+
+    pub fn update_game_odds(ctx: Context<UpdateOddsContext>, odds_bps: Vec<u32>, max_exposure_bps: u16) -> Result<()> {
+        return update_odds(ctx, odds_bps, max_exposure_bps);
+    }
+
+    pub fn initialize_game_lp_pool(ctx: Context<InitializeLpPoolContext>) -> Result<()> {
+        return initialize_lp_pool(ctx);
+    }
+
+    pub fn deposit_game_liquidity(ctx: Context<DepositLiquidityContext>, amount: u64) -> Result<()> {
+        return deposit_liquidity(ctx, amount);
+    }
+
+    pub fn request_liquidity_withdrawal(ctx: Context<RequestWithdrawalContext>, shares: u64) -> Result<()> {
+        return request_withdrawal(ctx, shares);
+    }
+
+    pub fn process_liquidity_withdrawal(ctx: Context<ProcessWithdrawalContext>) -> Result<()> {
+        return process_withdrawal(ctx);
+    }
+
+    pub fn update_game_seed_amount(ctx: Context<UpdateSeedAmountContext>, seed_amount: u64) -> Result<()> {
+        return update_seed_amount(ctx, seed_amount);
+    }
+
+    pub fn update_game_cancel_penalty(ctx: Context<UpdateCancelPenaltyContext>, cancel_penalty_bps: u16) -> Result<()> {
+        return update_cancel_penalty(ctx, cancel_penalty_bps);
+    }
+
+    pub fn update_game_switch_fee(ctx: Context<UpdateSwitchFeeContext>, switch_fee_bps: u16) -> Result<()> {
+        return update_switch_fee(ctx, switch_fee_bps);
+    }
+
+    pub fn update_game_tokenized_bets(ctx: Context<UpdateTokenizedBetsContext>, tokenized_bets: bool) -> Result<()> {
+        return update_tokenized_bets(ctx, tokenized_bets);
+    }
+
+    pub fn update_game_early_exit(ctx: Context<UpdateEarlyExitContext>, early_exit: bool, exit_spread_bps: u16) -> Result<()> {
+        return update_early_exit(ctx, early_exit, exit_spread_bps);
+    }
+
+    pub fn update_game_allowlist(ctx: Context<UpdateAllowlistContext>, allowlist_root: [u8; 32]) -> Result<()> {
+        return update_allowlist(ctx, allowlist_root);
+    }
+
+    pub fn update_game_commit_reveal(ctx: Context<UpdateCommitRevealContext>, commit_reveal: bool, reveal_window: u64, unrevealed_policy: states::UnrevealedPolicy) -> Result<()> {
+        return update_commit_reveal(ctx, commit_reveal, reveal_window, unrevealed_policy);
+    }
+
+    pub fn update_game_settlement_fee(ctx: Context<UpdateSettlementFeeContext>, settlement_fee_bps: u16) -> Result<()> {
+        return update_settlement_fee(ctx, settlement_fee_bps);
+    }
+
+    pub fn update_game_referral_share(ctx: Context<UpdateReferralShareContext>, referral_share_bps: u16) -> Result<()> {
+        return update_referral_share(ctx, referral_share_bps);
+    }
+
+    pub fn initialize_game_points(ctx: Context<InitializePointsContext>) -> Result<()> {
+        return initialize_points(ctx);
+    }
+
+    pub fn update_game_points(ctx: Context<UpdatePointsContext>, points_enabled: bool, points_rate_bps: u32, streak_bonus_bps: u16) -> Result<()> {
+        return update_points(ctx, points_enabled, points_rate_bps, streak_bonus_bps);
+    }
+
     pub fn test_feed(ctx: Context<PriceFeedContext>, feed_id: String, maximum_age: u64) -> Result<()> {
         return test_price_feed(ctx, feed_id, maximum_age);
     }
@@ -0,0 +1,26 @@
+use crate::states::*;
+
+// Early exit pricing, mirrored by getExitValue in the client helpers.
+
+// probability in bps that a directional outcome wins, implied by the pool and shifted by the price move so far
+pub fn win_probability_bps(implied_bps: u64, outcome: u8, move_bps: i64) -> u64 {
+    let shift = move_bps.saturating_mul(EXIT_MOVE_SENSITIVITY);
+    let shift = if outcome == OUTCOME_BEAR { -shift } else { shift };
+    (implied_bps as i64).saturating_add(shift).clamp(0, BPS_DENOMINATOR as i64) as u64
+}
+
+// price the house pays to take over a position before settlement
+pub fn exit_value(payout: u64, implied_bps: u64, outcome: u8, move_bps: i64, spread_bps: u16) -> u64 {
+    let probability = win_probability_bps(implied_bps, outcome, move_bps);
+    let fair_value = payout as u128 * probability as u128 / BPS_DENOMINATOR as u128;
+    (fair_value * (BPS_DENOMINATOR - spread_bps as u64) as u128 / BPS_DENOMINATOR as u128) as u64
+}
+
+// price move since the start of the round in bps
+pub fn move_bps(start_price: i64, price: i64) -> i64 {
+    if start_price == 0 {
+        return 0;
+    }
+    let change = (price as i128 - start_price as i128) * BPS_DENOMINATOR as i128 / start_price as i128;
+    change.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
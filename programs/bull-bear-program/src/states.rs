@@ -4,8 +4,38 @@ pub const PROTOCOL_SEED: &str = "PROTOCOL_SEED";
 pub const GAME_SEED: &str = "GAME_SEED";
 pub const ROUND_SEED: &str = "ROUND_SEED";
 pub const BET_SEED: &str = "BET_SEED";
+pub const LP_MINT_SEED: &str = "LP_MINT_SEED";
+pub const WITHDRAWAL_SEED: &str = "WITHDRAWAL_SEED";
+pub const RECEIPT_SEED: &str = "RECEIPT_SEED";
+pub const AUTO_BET_SEED: &str = "AUTO_BET_SEED";
+pub const BET_NONCE_SEED: &str = "BET_NONCE_SEED";
+pub const SESSION_SEED: &str = "SESSION_SEED";
+pub const PLAYER_STATS_SEED: &str = "PLAYER_STATS_SEED";
+pub const SEASON_SEED: &str = "SEASON_SEED";
+pub const SEASON_ENTRY_SEED: &str = "SEASON_ENTRY_SEED";
+pub const REFERRAL_SEED: &str = "REFERRAL_SEED";
+pub const REFERRAL_REWARDS_SEED: &str = "REFERRAL_REWARDS_SEED";
+pub const POINTS_MINT_SEED: &str = "POINTS_MINT_SEED";
 
 pub const MAXIMUM_AGE: u64 = 600; // 10 minute
+pub const MAX_PRICE_SAMPLES: usize = 10;
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+// outcomes, directional markets use the first three
+pub const MAX_OUTCOMES: usize = 8;
+pub const OUTCOME_BULL: u8 = 0;
+pub const OUTCOME_BEAR: u8 = 1;
+pub const OUTCOME_FLAT: u8 = 2;
+pub const NO_OUTCOME: u8 = u8::MAX;
+
+// probability shift in bps per bps of price move used to price early exits
+pub const EXIT_MOVE_SENSITIVITY: i64 = 10;
+
+// number of paid places on a season leaderboard
+pub const MAX_SEASON_WINNERS: usize = 10;
+
+// longest winning streak raising the loyalty points rate
+pub const MAX_POINTS_STREAK: u32 = 10;
 
 
 /** PROTOCOL */
@@ -30,8 +60,200 @@ pub struct Game {
     pub vault: Pubkey,
     pub token: Pubkey,
     pub bump: u8,
+    pub legacy_game: Pubkey,
+    pub twap_window: u64,
+    pub price_source: PriceSource,
+    pub max_ema_deviation_bps: u16,
+    pub flat_band_bps: u16,
+    pub flat_policy: FlatPolicy,
+    pub rollover: u64,
+    pub market: MarketType,
+    pub num_buckets: u8,
+    pub bucket_bounds: [i32; MAX_OUTCOMES - 1],
+    pub feed_id_b: [u8; 32],
+    pub feed_account_b: Pubkey,
+    pub fixed_odds: bool,
+    pub odds_bps: [u32; MAX_OUTCOMES],
+    pub max_exposure_bps: u16,
+    pub exposure: u64,
+    pub lp_mint: Pubkey,
+    pub queued_shares: u64,
+    pub seed_amount: u64,
+    pub cancel_penalty_bps: u16,
+    pub switch_fee_bps: u16,
+    pub tokenized_bets: bool,
+    pub early_exit: bool,
+    pub exit_spread_bps: u16,
+    pub allowlist_root: [u8; 32],
+    pub commit_reveal: bool,
+    pub reveal_window: u64,
+    pub unrevealed_policy: UnrevealedPolicy,
+    pub settlement_fee_bps: u16,
+    pub season: Pubkey,
+    pub season_end: i64,
+    pub referral_share_bps: u16,
+    pub referral_liability: u64,
+    pub points_mint: Pubkey,
+    pub points_enabled: bool,
+    pub points_rate_bps: u32,
+    pub streak_bonus_bps: u16,
+}
+
+impl Game {
+    // number of outcomes players can bet on
+    pub fn num_outcomes(&self) -> u8 {
+        match self.market {
+            MarketType::TwoWay => 2,
+            MarketType::ThreeWay => 3,
+            MarketType::HeadToHead => 2,
+            MarketType::Buckets => self.num_buckets,
+        }
+    }
+
+    // vault funds owned by the house, excluding rollover, funds backing open fixed-odds bets and accrued referral rewards
+    pub fn house_funds(&self, vault_balance: u64) -> u64 {
+        vault_balance.saturating_sub(self.rollover + self.exposure + self.referral_liability)
+    }
+
+    // last moment to reveal committed bets, the reveal phase follows the betting phase
+    pub fn reveal_deadline(&self, start_time: i64) -> i64 {
+        start_time + (self.round_interval / 2 + self.reveal_window) as i64
+    }
+
+    // loyalty points for a stake, each win of the current streak adds the streak bonus to the rate
+    pub fn points_for(&self, amount: u64, streak: u32) -> u64 {
+        let multiplier_bps = BPS_DENOMINATOR as u128 + streak.min(MAX_POINTS_STREAK) as u128 * self.streak_bonus_bps as u128;
+        (amount as u128 * self.points_rate_bps as u128 * multiplier_bps / (BPS_DENOMINATOR as u128 * BPS_DENOMINATOR as u128)) as u64
+    }
+
+    // bucket of a price change in bps, bounds belong to the upper bucket
+    pub fn bucket_of(&self, change_bps: i64) -> u8 {
+        let num_bounds = (self.num_buckets as usize).saturating_sub(1);
+        self.bucket_bounds[..num_bounds].iter().filter(|bound| **bound as i64 <= change_bps).count() as u8
+    }
+}
+
+/** LEGACY GAMES (seeded by feed account) */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct LegacyGame {
+    pub protocol: Pubkey,
+    pub game_authority: Pubkey,
+    pub counter: u16,
+    pub round_interval: u64,
+    pub feed_id: [u8; 32],
+    pub feed_account: Pubkey,
+    pub vault: Pubkey,
+    pub token: Pubkey,
+    pub bump: u8,
+}
+
+/** LEGACY ROUNDS AND BETS (two-sided pools, before outcome markets) */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, InitSpace)]
+pub struct LegacyRound {
+    pub game: Pubkey,
+    pub round_nr: u16,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub start_price: i64,
+    pub end_price: i64,
+    pub total_up: u64,
+    pub total_down: u64,
+    pub betting: BettingStatus,
+    pub result: PriceMovement,
+    pub status: RoundStatus,
+    pub num_bets: u16,
+    pub bump: u8,
+}
+
+impl LegacyRound {
+    // legacy rounds paid the winning side and left flat rounds to the house
+    pub fn into_round(self) -> Round {
+        let winning_outcome = match (&self.status, &self.result) {
+            (RoundStatus::Ended, PriceMovement::Bull) => OUTCOME_BULL,
+            (RoundStatus::Ended, PriceMovement::Bear) => OUTCOME_BEAR,
+            _ => NO_OUTCOME,
+        };
+        let mut totals = [0; MAX_OUTCOMES];
+        totals[OUTCOME_BULL as usize] = self.total_up;
+        totals[OUTCOME_BEAR as usize] = self.total_down;
+
+        Round {
+            game: self.game,
+            round_nr: self.round_nr,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            start_price: self.start_price,
+            end_price: self.end_price,
+            totals,
+            betting: self.betting,
+            result: self.result,
+            status: self.status,
+            num_bets: self.num_bets,
+            bump: self.bump,
+            start_samples: PriceSamples::default(),
+            end_samples: PriceSamples::default(),
+            bonus: 0,
+            flat_policy: FlatPolicy::HouseTake,
+            winning_outcome,
+            start_price_b: 0,
+            end_price_b: 0,
+            payouts: [0; MAX_OUTCOMES],
+            seeds: [0; MAX_OUTCOMES],
+            exited: [0; MAX_OUTCOMES],
+            committed: 0,
+            fee: 0,
+            season_fee: 0,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, InitSpace)]
+pub struct LegacyBet {
+    pub player: Pubkey,
+    pub round: Pubkey,
+    pub prediction: PriceMovement,
+    pub amount: u64,
+    pub claimed: bool,
+    pub bump: u8,
 }
 
+impl LegacyBet {
+    pub fn into_bet(self) -> Bet {
+        let outcome = match self.prediction {
+            PriceMovement::Bull => OUTCOME_BULL,
+            PriceMovement::Bear => OUTCOME_BEAR,
+            PriceMovement::NoChange => OUTCOME_FLAT,
+            PriceMovement::None => NO_OUTCOME,
+        };
+
+        Bet {
+            player: self.player,
+            round: self.round,
+            outcome,
+            amount: self.amount,
+            claimed: self.claimed,
+            bump: self.bump,
+            odds_bps: 0,
+            receipt_mint: Pubkey::default(),
+            status: BetStatus::Active,
+            commitment: [0; 32],
+            season: Pubkey::default(),
+            referrer: Pubkey::default(),
+            points: 0,
+        }
+    }
+}
+
+
+/** LIQUIDITY WITHDRAWALS */
+#[account]
+#[derive(InitSpace)]
+pub struct LpWithdrawal {
+    pub provider: Pubkey,
+    pub game: Pubkey,
+    pub shares: u64,
+    pub bump: u8,
+}
 
 /** ROUNDS */
 #[account]
@@ -43,13 +265,123 @@ pub struct Round {
     pub end_time: i64,
     pub start_price: i64,
     pub end_price: i64,
-    pub total_up: u64,
-    pub total_down: u64,
+    pub totals: [u64; MAX_OUTCOMES],
     pub betting: BettingStatus,
     pub result: PriceMovement,
     pub status: RoundStatus,
     pub num_bets: u16,
     pub bump: u8,
+    pub start_samples: PriceSamples,
+    pub end_samples: PriceSamples,
+    pub bonus: u64,
+    pub flat_policy: FlatPolicy,
+    pub winning_outcome: u8,
+    pub start_price_b: i64,
+    pub end_price_b: i64,
+    pub payouts: [u64; MAX_OUTCOMES],
+    pub seeds: [u64; MAX_OUTCOMES],
+    pub exited: [u64; MAX_OUTCOMES],
+    pub committed: u64,
+    pub fee: u64,
+    pub season_fee: u64,
+}
+
+impl Round {
+    pub fn total_pool(&self) -> u64 {
+        self.totals.iter().sum()
+    }
+
+    // player stakes, rolled over funds and house seeds, less the settlement fee
+    pub fn prize_pool(&self) -> u64 {
+        self.total_pool() + self.bonus + self.seeds.iter().sum::<u64>() - self.fee
+    }
+
+    // share of the prize pool won by a stake on the winning outcome (house seeds share alongside players)
+    pub fn prize_share(&self, amount: u64, outcome: u8) -> u64 {
+        let winning_stakes = self.totals[outcome as usize] + self.seeds[outcome as usize];
+        if winning_stakes == 0 {
+            return 0;
+        }
+        (amount as u128 * self.prize_pool() as u128 / winning_stakes as u128) as u64
+    }
+
+    // part of the settlement fee kept by the game vault (net of the season share) borne by a stake on the winning outcome
+    pub fn house_fee_share(&self, amount: u64, outcome: u8) -> u64 {
+        let winning_stakes = self.totals[outcome as usize] + self.seeds[outcome as usize];
+        if winning_stakes == 0 {
+            return 0;
+        }
+        (amount as u128 * (self.fee - self.season_fee) as u128 / winning_stakes as u128) as u64
+    }
+
+    // rounds without winner under refund policy return the stakes
+    pub fn is_refund(&self) -> bool {
+        self.winning_outcome == NO_OUTCOME && self.flat_policy == FlatPolicy::Refund
+    }
+
+    // worst case loss of the house on fixed-odds bets
+    pub fn house_exposure(&self) -> u64 {
+        let max_payout = self.payouts.iter().max().copied().unwrap_or(0);
+        max_payout.saturating_sub(self.total_pool() + self.bonus)
+    }
+}
+
+/** PRICE SAMPLES */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Default, InitSpace)]
+pub struct PriceSample {
+    pub price: i64,
+    pub publish_time: i64,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Default, InitSpace)]
+pub struct PriceSamples {
+    pub samples: [PriceSample; MAX_PRICE_SAMPLES],
+    pub head: u8,
+    pub len: u8,
+}
+
+impl PriceSamples {
+    // ring buffer, overwrites the oldest sample when full
+    pub fn push(&mut self, sample: PriceSample) {
+        self.samples[self.head as usize] = sample;
+        self.head = ((self.head as usize + 1) % MAX_PRICE_SAMPLES) as u8;
+        if (self.len as usize) < MAX_PRICE_SAMPLES {
+            self.len += 1;
+        }
+    }
+
+    pub fn last(&self) -> Option<PriceSample> {
+        if self.len == 0 {
+            return None;
+        }
+        Some(self.samples[(self.head as usize + MAX_PRICE_SAMPLES - 1) % MAX_PRICE_SAMPLES])
+    }
+
+    // time-weighted average over [from, until], each sample holds until the next one is published
+    pub fn twap(&self, from: i64, until: i64) -> Option<i64> {
+        let last = self.last()?;
+        let until = until.max(from);
+        let oldest = (self.head as usize + MAX_PRICE_SAMPLES - self.len as usize) % MAX_PRICE_SAMPLES;
+
+        let mut weighted_sum: i128 = 0;
+        let mut total_time: i128 = 0;
+        for i in 0..self.len as usize {
+            let sample = self.samples[(oldest + i) % MAX_PRICE_SAMPLES];
+            let next_time = if i + 1 < self.len as usize {
+                self.samples[(oldest + i + 1) % MAX_PRICE_SAMPLES].publish_time
+            } else {
+                until
+            };
+            let duration = next_time.clamp(from, until) - sample.publish_time.clamp(from, until);
+            weighted_sum += sample.price as i128 * duration as i128;
+            total_time += duration as i128;
+        }
+
+        if total_time <= 0 {
+            return Some(last.price);
+        }
+        Some((weighted_sum / total_time) as i64)
+    }
 }
 
 /** BETS */
@@ -58,12 +390,196 @@ pub struct Round {
 pub struct Bet {
     pub player: Pubkey,
     pub round: Pubkey,
-    pub prediction: PriceMovement,
+    pub outcome: u8,
+    pub amount: u64,
+    pub claimed: bool,
+    pub bump: u8,
+    pub odds_bps: u32,
+    pub receipt_mint: Pubkey,
+    pub status: BetStatus,
+    pub commitment: [u8; 32],
+    pub season: Pubkey,
+    pub referrer: Pubkey,
+    pub points: u64,
+}
+
+impl Bet {
+    pub fn is_tokenized(&self) -> bool {
+        self.receipt_mint != Pubkey::default()
+    }
+
+    // payout including the stake, zero for parimutuel bets
+    pub fn fixed_payout(&self) -> u64 {
+        (self.amount as u128 * self.odds_bps as u128 / BPS_DENOMINATOR as u128) as u64
+    }
+}
+
+/** AUTO BETS */
+#[account]
+#[derive(InitSpace)]
+pub struct AutoBet {
+    pub player: Pubkey,
+    pub game: Pubkey,
+    pub outcome: u8,
     pub amount: u64,
+    pub remaining: u16,
+    pub bump: u8,
+}
+
+/** SIGNED BETS */
+#[account]
+#[derive(InitSpace)]
+pub struct BetNonce {
+    pub player: Pubkey,
+    pub nonce: u64,
+    pub bump: u8,
+}
+
+// bet signed off-chain by the player, submitted by a relayer
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct BetIntent {
+    pub player: Pubkey,
+    pub game: Pubkey,
+    pub round: Pubkey,
+    pub outcome: u8,
+    pub amount: u64,
+    pub nonce: u64,
+    pub expiry: i64,
+}
+
+/** SESSIONS */
+#[account]
+#[derive(InitSpace)]
+pub struct Session {
+    pub player: Pubkey,
+    pub session_key: Pubkey,
+    pub expiry: i64,
+    pub spend_limit: u64,
+    pub spent: u64,
+    pub bump: u8,
+}
+
+/** PLAYER STATS */
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerStats {
+    pub player: Pubkey,
+    pub game: Pubkey,
+    pub total_wagered: u64,
+    pub total_won: u64,
+    pub wins: u32,
+    pub losses: u32,
+    pub current_streak: u32,
+    pub best_streak: u32,
+    pub last_round: u16,
+    pub previous_round: u16,
+    pub last_bet: Pubkey,
+    pub bump: u8,
+}
+
+impl PlayerStats {
+    // the wager stays pending until its round is settled
+    pub fn record_wager(&mut self, bet: Pubkey, round_nr: u16, amount: u64) {
+        self.total_wagered += amount;
+        self.previous_round = self.last_round;
+        self.last_round = round_nr;
+        self.last_bet = bet;
+    }
+
+    // the cancelled round no longer counts as played
+    pub fn undo_wager(&mut self, amount: u64) {
+        self.total_wagered -= amount;
+        self.last_round = self.previous_round;
+        self.last_bet = Pubkey::default();
+    }
+
+    // streaks follow the order in which results are recorded
+    pub fn record_win(&mut self, prize: u64) {
+        self.total_won += prize;
+        self.wins += 1;
+        self.current_streak += 1;
+        self.best_streak = self.best_streak.max(self.current_streak);
+    }
+
+    pub fn record_loss(&mut self) {
+        self.losses += 1;
+        self.current_streak = 0;
+    }
+}
+
+/** SEASONS */
+#[account]
+#[derive(InitSpace)]
+pub struct Season {
+    pub game: Pubkey,
+    pub season_nr: u16,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub fee_share_bps: u16,
+    pub payout_bps: [u16; MAX_SEASON_WINNERS],
+    pub num_winners: u8,
+    pub challenge_period: u64,
+    pub num_entries: u32,
+    pub ranking: [Pubkey; MAX_SEASON_WINNERS],
+    pub num_ranked: u8,
+    pub ranked_at: i64,
+    pub cutoff: i64,
+    pub prize_total: u64,
+    pub bump: u8,
+}
+
+impl Season {
+    pub fn is_live(&self, now: i64) -> bool {
+        self.start_time <= now && now <= self.end_time
+    }
+
+    // a committed ranking becomes final once it survived the challenge period
+    pub fn is_final(&self, now: i64) -> bool {
+        self.ranked_at > 0 && now >= self.ranked_at + self.challenge_period as i64
+    }
+
+    pub fn rank_of(&self, player: &Pubkey) -> Option<usize> {
+        self.ranking[..self.num_ranked as usize].iter().position(|ranked| ranked == player)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct SeasonEntry {
+    pub season: Pubkey,
+    pub player: Pubkey,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub net_winnings: i64,
     pub claimed: bool,
     pub bump: u8,
 }
 
+impl SeasonEntry {
+    pub fn is_live(&self, now: i64) -> bool {
+        self.start_time <= now && now <= self.end_time
+    }
+}
+
+/** REFERRALS */
+#[account]
+#[derive(InitSpace)]
+pub struct Referral {
+    pub player: Pubkey,
+    pub referrer: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ReferralRewards {
+    pub referrer: Pubkey,
+    pub game: Pubkey,
+    pub accrued: u64,
+    pub claimed: u64,
+    pub bump: u8,
+}
+
 /** ENUMS */
 #[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, InitSpace)]
 pub enum BettingStatus {
@@ -79,6 +595,40 @@ pub enum PriceMovement {
     NoChange,
 }
 
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, InitSpace)]
+pub enum PriceSource {
+    Spot,
+    Ema,
+    SpotWithEmaGuard,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, InitSpace)]
+pub enum FlatPolicy {
+    HouseTake,
+    Refund,
+    Rollover,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, InitSpace)]
+pub enum UnrevealedPolicy {
+    House,
+    Pool,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, InitSpace)]
+pub enum MarketType {
+    TwoWay,
+    ThreeWay,
+    Buckets,
+    HeadToHead,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, InitSpace)]
+pub enum BetStatus {
+    Active,
+    Exited,
+}
+
 #[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, InitSpace)]
 pub enum RoundStatus {
     Active,
@@ -0,0 +1,445 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
+use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+use pyth_solana_receiver_sdk::price_update::{PriceUpdateV2, VerificationLevel};
+
+use crate::errors::BullBearProgramError;
+use crate::states::*;
+
+// checks that the price account carries the feed and delivers a valid and fresh price
+pub fn validate_price_feed(price_update: &PriceUpdateV2, feed_id: &[u8; 32], maximum_age: u64) -> Result<()> {
+
+    require!(price_update.price_message.feed_id == *feed_id, BullBearProgramError::FeedIdMismatch);
+
+    let price = price_update.get_price_no_older_than_with_custom_verification_level(&Clock::get()?,
+        maximum_age,
+        feed_id,VerificationLevel::Partial{num_signatures: 1})?;
+    require!(price.price > 0, BullBearProgramError::InvalidPrice);
+
+    Ok(())
+}
+
+// reads the price of a game feed from the source configured for the game
+pub fn get_game_price(price_update: &PriceUpdateV2, feed_id: &[u8; 32], game: &Game, maximum_age: u64, verification_level: VerificationLevel) -> Result<PriceSample> {
+
+    let price = price_update.get_price_no_older_than_with_custom_verification_level(&Clock::get()?,
+        maximum_age,
+        feed_id,verification_level)?;
+
+    // ema is part of the same (verified) price message
+    let ema_price = price_update.price_message.ema_price;
+
+    let game_price = match game.price_source {
+        PriceSource::Spot => price.price,
+        PriceSource::Ema => ema_price,
+        PriceSource::SpotWithEmaGuard => {
+            let deviation = (price.price as i128 - ema_price as i128).unsigned_abs();
+            let max_deviation = ema_price.unsigned_abs() as u128 * game.max_ema_deviation_bps as u128;
+            require!(deviation * BPS_DENOMINATOR as u128 <= max_deviation, BullBearProgramError::PriceDeviatesFromEma);
+            price.price
+        }
+    };
+
+    Ok(PriceSample{price: game_price, publish_time: price.publish_time})
+}
+
+// checks that the round at the game counter is not running (uninitialized rounds are fine)
+pub fn require_between_rounds(round: &AccountInfo) -> Result<()> {
+
+    if round.data_is_empty() {
+        return Ok(());
+    }
+
+    let data = round.try_borrow_data()?;
+    let round = Round::try_deserialize(&mut &data[..])?;
+    require!(round.status != RoundStatus::Active, BullBearProgramError::RoundInProgress);
+
+    Ok(())
+}
+
+// reads an account of the program that is still in a legacy layout
+pub fn read_legacy_account<T: AnchorDeserialize>(info: &AccountInfo, discriminator: &[u8], space: usize) -> Result<T> {
+
+    let data = info.try_borrow_data()?;
+    require!(data.len() == 8 + space && data[..8] == *discriminator, BullBearProgramError::InvalidLegacyAccount);
+
+    Ok(T::deserialize(&mut &data[8..])?)
+}
+
+// rewrites an account in its current layout, the payer tops up the rent for the grown account
+pub fn rewrite_account<'info, T: AccountSerialize>(info: &AccountInfo<'info>, payer: &AccountInfo<'info>, system_program: &AccountInfo<'info>, account: &T, space: usize) -> Result<()> {
+
+    let rent = Rent::get()?.minimum_balance(8 + space);
+    let missing = rent.saturating_sub(info.lamports());
+    if missing > 0 {
+        let cpi_context = CpiContext::new(
+            system_program.clone(),
+            Transfer{from: payer.clone(), to: info.clone()}
+        );
+        transfer(cpi_context, missing)?;
+    }
+
+    info.realloc(8 + space, true)?;
+    account.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+// checks that a token account has no allowance for another delegate (token accounts have a single delegate)
+pub fn require_free_delegate(token_account: &TokenAccount, delegate: &Pubkey) -> Result<()> {
+
+    let in_use = token_account.delegate.is_some() && token_account.delegated_amount > 0;
+    require!(!in_use || token_account.delegate == COption::Some(*delegate), BullBearProgramError::DelegateInUse);
+
+    Ok(())
+}
+
+// checks whether a token account delegates to the given account
+pub fn is_delegate(token_account: &TokenAccount, delegate: &Pubkey) -> bool {
+    token_account.delegate == COption::Some(*delegate)
+}
+
+// checks that the worst case loss of the round stays within the exposure limit and records it
+pub fn update_exposure(game: &mut Game, round: &Round, vault_balance: u64) -> Result<()> {
+
+    let exposure = round.house_exposure();
+    let house_funds = vault_balance.saturating_sub(game.rollover);
+    let max_exposure = (house_funds as u128 * game.max_exposure_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+    require!(exposure <= max_exposure, BullBearProgramError::ExposureLimitExceeded);
+
+    game.exposure = exposure;
+
+    Ok(())
+}
+
+// records a new bet in the round pool, fixed-odds bets are quoted at the game odds
+pub fn record_bet(game: &mut Game, round: &mut Round, bet: &mut Bet, player: Pubkey, outcome: u8, amount: u64, vault_balance: u64, proof: &[[u8; 32]]) -> Result<()> {
+
+    // check if player may bet in private games
+    verify_allowlist(game, &player, proof)?;
+
+    // check if round active
+    require!(round.status == RoundStatus::Active, BullBearProgramError::RoundNotActive);
+    // check if betting open
+    require!(round.betting == BettingStatus::Open, BullBearProgramError::BettingIsClosed);
+
+    // check that the side does not have to be committed
+    require!(!game.commit_reveal, BullBearProgramError::CommitRevealRequired);
+    // check if outcome exists in the game market
+    require!(outcome < game.num_outcomes(), BullBearProgramError::InvalidPrediction);
+    // check that the bet has a stake
+    require!(amount > 0, BullBearProgramError::InvalidBetAmount);
+
+    let total = &mut round.totals[outcome as usize];
+    *total = total.checked_add(amount).ok_or(BullBearProgramError::MaximumBetAmountReached)?;
+
+    bet.player = player;
+    bet.outcome = outcome;
+    bet.amount = amount;
+    bet.claimed = false;
+    bet.odds_bps = 0;
+    bet.receipt_mint = Pubkey::default();
+    bet.status = BetStatus::Active;
+    bet.commitment = [0; 32];
+    bet.season = Pubkey::default();
+    bet.referrer = Pubkey::default();
+    bet.points = 0;
+
+    // fixed-odds bets are backed by the game vault at the quoted odds
+    if game.fixed_odds {
+        let odds_bps = game.odds_bps[outcome as usize];
+        require!(odds_bps > 0, BullBearProgramError::OddsNotQuoted);
+        bet.odds_bps = odds_bps;
+
+        let payout = &mut round.payouts[outcome as usize];
+        *payout = payout.checked_add(bet.fixed_payout()).ok_or(BullBearProgramError::MaximumBetAmountReached)?;
+
+        update_exposure(game, round, vault_balance)?;
+    }
+
+    round.num_bets += 1;
+
+    Ok(())
+}
+
+// loyalty points for the stake of a bet are minted once it settles, the winning streak of the settled stats raises the rate
+pub fn record_bet_points(game: &Game, bet: &mut Bet, streak: u32) {
+
+    if game.points_enabled {
+        bet.points = game.points_for(bet.amount, streak);
+    }
+}
+
+// passes the referrer bound by the player on to the bet
+pub fn record_referrer(referral: &AccountInfo, bet: &mut Bet) -> Result<()> {
+
+    // players without referral place unreferred bets
+    if let Some(referral) = load_opened::<Referral>(referral)? {
+        bet.referrer = referral.referrer;
+    }
+
+    Ok(())
+}
+
+// checks that the previous instruction verified a single ed25519 signature of the signer over the message
+pub fn verify_ed25519_signature(instructions: &AccountInfo, signer: &Pubkey, message: &[u8]) -> Result<()> {
+
+    let current_index = load_current_index_checked(instructions)?;
+    require!(current_index > 0, BullBearProgramError::InvalidSignature);
+
+    let ix = load_instruction_at_checked((current_index - 1) as usize, instructions)?;
+    require!(ix.program_id == ed25519_program::ID && ix.accounts.is_empty(), BullBearProgramError::InvalidSignature);
+
+    // header: count and padding, followed by the offsets of the signature, public key and message
+    let data = &ix.data;
+    require!(data.len() >= 16 && data[0] == 1, BullBearProgramError::InvalidSignature);
+    let read_u16 = |i: usize| u16::from_le_bytes([data[i], data[i + 1]]);
+
+    // offsets need to point into the ed25519 instruction itself
+    let this_instruction = u16::MAX;
+    require!(read_u16(4) == this_instruction
+        && read_u16(8) == this_instruction
+        && read_u16(14) == this_instruction, BullBearProgramError::InvalidSignature);
+
+    let public_key_offset = read_u16(6) as usize;
+    let message_offset = read_u16(10) as usize;
+    let message_size = read_u16(12) as usize;
+    require!(data.len() >= public_key_offset + 32 && data.len() >= message_offset + message_size, BullBearProgramError::InvalidSignature);
+
+    require!(&data[public_key_offset..public_key_offset + 32] == signer.as_ref(), BullBearProgramError::InvalidSignature);
+    require!(&data[message_offset..message_offset + message_size] == message, BullBearProgramError::InvalidSignature);
+
+    Ok(())
+}
+
+// loads an account of the program at a checked address, None while it is not opened
+pub fn load_opened<T: AccountDeserialize>(info: &AccountInfo) -> Result<Option<T>> {
+
+    if info.data_is_empty() {
+        return Ok(None);
+    }
+    require!(*info.owner == crate::ID, anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram);
+
+    let data = info.try_borrow_data()?;
+    Ok(Some(T::try_deserialize(&mut &data[..])?))
+}
+
+// writes back an account loaded with load_opened
+pub fn store_opened<T: AccountSerialize>(info: &AccountInfo, account: &T) -> Result<()> {
+
+    let mut data = info.try_borrow_mut_data()?;
+    account.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
+
+// records the result of the pending bet once its round ended (refunds and exited bets count neither way)
+pub fn settle_player_stats(player_stats: &mut PlayerStats, round: &Round, bet: &Bet) -> Result<()> {
+
+    // check if round ended
+    require!(round.status == RoundStatus::Ended, BullBearProgramError::CurrentRoundNotEnded);
+
+    if bet.status == BetStatus::Active && !round.is_refund() {
+        if bet.outcome != NO_OUTCOME && bet.outcome == round.winning_outcome {
+            player_stats.record_win(bet_prize(round, bet));
+        }
+        else {
+            player_stats.record_loss();
+        }
+    }
+    player_stats.last_bet = Pubkey::default();
+
+    Ok(())
+}
+
+// settles the pending bet of opened player stats and records the new wager, so results are recorded in order
+pub fn record_player_wager(player_stats: &AccountInfo, last_bet: Option<&Account<Bet>>, last_round: Option<&Account<Round>>, bet: Pubkey, round_nr: u16, amount: u64) -> Result<u32> {
+
+    let mut stats = match load_opened::<PlayerStats>(player_stats)? {
+        Some(stats) => stats,
+        None => return Ok(0),
+    };
+
+    if stats.last_bet != Pubkey::default() {
+        let (last_bet, last_round) = last_bet.zip(last_round).ok_or(BullBearProgramError::PendingBetNotSettled)?;
+        require!(last_bet.key() == stats.last_bet && last_bet.round == last_round.key(), BullBearProgramError::PendingBetNotSettled);
+        settle_player_stats(&mut stats, last_round, last_bet)?;
+    }
+    stats.record_wager(bet, round_nr, amount);
+    store_opened(player_stats, &stats)?;
+
+    // winning streak once the previous bet is settled
+    Ok(stats.current_streak)
+}
+
+// settles a bet in opened player stats while it is their pending bet (later wagers settled it otherwise)
+pub fn settle_player_bet(player_stats: &AccountInfo, bet_key: Pubkey, round: &Round, bet: &Bet) -> Result<()> {
+
+    if let Some(mut stats) = load_opened::<PlayerStats>(player_stats)? {
+        if stats.last_bet == bet_key {
+            settle_player_stats(&mut stats, round, bet)?;
+            store_opened(player_stats, &stats)?;
+        }
+    }
+
+    Ok(())
+}
+
+// removes a cancelled bet from opened player stats while it is their pending bet
+pub fn undo_player_wager(player_stats: &AccountInfo, bet_key: Pubkey, amount: u64) -> Result<()> {
+
+    if let Some(mut stats) = load_opened::<PlayerStats>(player_stats)? {
+        if stats.last_bet == bet_key {
+            stats.undo_wager(amount);
+            store_opened(player_stats, &stats)?;
+        }
+    }
+
+    Ok(())
+}
+
+// counts the stake of a bet against the opened season entry of the player while the season runs
+pub fn record_season_stake(game: &Game, season_entry: &AccountInfo, bet: &mut Bet, now: i64) -> Result<()> {
+
+    if let Some(mut entry) = load_opened::<SeasonEntry>(season_entry)? {
+        if entry.is_live(now) {
+            entry.net_winnings -= bet.amount as i64;
+            bet.season = game.season;
+            store_opened(season_entry, &entry)?;
+        }
+    }
+
+    Ok(())
+}
+
+// credits tokens returned on a bet to the season entry its stake counted against, until the season ranking is committed
+pub fn record_season_return(season: Option<&Account<Season>>, season_entry: &AccountInfo, bet: &Bet, amount: u64) -> Result<()> {
+
+    // stakes outside of seasons are not tracked
+    if bet.season == Pubkey::default() {
+        return Ok(());
+    }
+    // check that the season of the stake is passed
+    let season = season.ok_or(BullBearProgramError::InvalidSeason)?;
+
+    if season.ranked_at == 0 {
+        if let Some(mut entry) = load_opened::<SeasonEntry>(season_entry)? {
+            entry.net_winnings += amount as i64;
+            store_opened(season_entry, &entry)?;
+        }
+    }
+
+    Ok(())
+}
+
+// mints the loyalty points of a settled or exited bet to its owner (refunded rounds earn nothing)
+pub fn mint_bet_points<'info>(game: &Account<'info, Game>, round: &Round, bet: &Bet, points_mint: Option<&Account<'info, Mint>>, points_account: Option<&Account<'info, TokenAccount>>, token_program: &Program<'info, Token>) -> Result<()> {
+
+    if bet.points == 0 || (round.status == RoundStatus::Ended && round.is_refund()) {
+        return Ok(());
+    }
+    let points_mint = points_mint.ok_or(BullBearProgramError::MissingPointsAccount)?;
+    let points_account = points_account.ok_or(BullBearProgramError::MissingPointsAccount)?;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[GAME_SEED.as_bytes(),
+            game.game_authority.as_ref(),
+            game.protocol.as_ref(),
+            game.token.as_ref(),
+            game.feed_id.as_ref(),
+            &[game.bump]]];
+    let cpi_context = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        MintTo{mint: points_mint.to_account_info(), to: points_account.to_account_info(), authority: game.to_account_info()},
+        signer_seeds
+    );
+
+    mint_to(cpi_context, bet.points)
+}
+
+// prize of a winning bet (refunds return the stake)
+pub fn bet_prize(round: &Round, bet: &Bet) -> u64 {
+
+    if round.is_refund() {
+        bet.amount
+    }
+    else if bet.odds_bps > 0 {
+        bet.fixed_payout()
+    }
+    else {
+        round.prize_share(bet.amount, bet.outcome)
+    }
+}
+
+// marks a bet as claimed and returns its prize (rolled over funds and house seeds are added to the pool)
+pub fn claim_bet(round: &Round, bet: &mut Bet) -> Result<u64> {
+
+    // check if round ended
+    require!(round.status == RoundStatus::Ended, BullBearProgramError::CurrentRoundNotEnded);
+    // rounds without winner under refund policy return the stakes
+    let refund = round.is_refund();
+    // check if claimable
+    require!(refund || round.winning_outcome == bet.outcome, BullBearProgramError::NoPrizeClaimable);
+    // check if side was revealed
+    require!(bet.outcome != NO_OUTCOME, BullBearProgramError::BetNotRevealed);
+    // check if already claimed
+    require!(bet.claimed == false, BullBearProgramError::PrizeAlreadyClaimed);
+    // check if position still belongs to the player
+    require!(bet.status == BetStatus::Active, BullBearProgramError::BetExited);
+
+    let prize = bet_prize(round, bet);
+
+    bet.claimed = true;
+
+    Ok(prize)
+}
+
+// credits the referrer of a claimed bet with its share of the settlement fee borne by the bet
+pub fn accrue_referral_reward(game: &mut Game, round: &Round, bet: &Bet, referral_rewards: &AccountInfo) -> Result<()> {
+
+    // bets without referrer and refunds accrue nothing
+    if bet.referrer == Pubkey::default() || round.is_refund() {
+        return Ok(());
+    }
+
+    // referrers accrue once they opened their rewards
+    if let Some(mut rewards) = load_opened::<ReferralRewards>(referral_rewards)? {
+        let fee = round.house_fee_share(bet.amount, bet.outcome);
+        let reward = (fee as u128 * game.referral_share_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+        rewards.accrued += reward;
+        // the reward is owed from the game vault until the referrer claims it
+        game.referral_liability += reward;
+        store_opened(referral_rewards, &rewards)?;
+    }
+
+    Ok(())
+}
+
+// checks a merkle proof of the player against the game allowlist (sha256 of the key as leaf, sorted pairs)
+pub fn verify_allowlist(game: &Game, player: &Pubkey, proof: &[[u8; 32]]) -> Result<()> {
+
+    // games without root are public
+    if game.allowlist_root == [0; 32] {
+        return Ok(());
+    }
+
+    let mut node = hashv(&[player.as_ref()]).to_bytes();
+    for sibling in proof {
+        node = if node <= *sibling {
+            hashv(&[node.as_ref(), sibling.as_ref()]).to_bytes()
+        } else {
+            hashv(&[sibling.as_ref(), node.as_ref()]).to_bytes()
+        };
+    }
+    require!(node == game.allowlist_root, BullBearProgramError::NotAllowlisted);
+
+    Ok(())
+}
+
+// commitment to the side of a bet, bound to the player
+pub fn bet_commitment(player: &Pubkey, outcome: u8, salt: &[u8; 32]) -> [u8; 32] {
+    hashv(&[player.as_ref(), &[outcome], salt.as_ref()]).to_bytes()
+}
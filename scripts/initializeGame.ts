@@ -60,7 +60,7 @@ export async function initializeGameInstruction(
     signer,
     protocolAddress,
     tokenAddress,
-    priceFeedAddr
+    priceFeedId
   );
 
   const gameVault = getAssociatedTokenAddressSync(
@@ -77,6 +77,7 @@ export async function initializeGameInstruction(
       game: gameAccount,
       mint: tokenAddress,
       vault: gameVault,
+      priceUpdate: priceFeedAddr,
       systemProgram: SystemProgram.programId,
       tokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
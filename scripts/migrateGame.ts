@@ -0,0 +1,144 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+} from "@solana/web3.js";
+import { BullBearProgram } from "../target/types/bull_bear_program";
+
+import fs from "fs";
+import path from "path";
+import os from "os";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { getGamePDA, getLegacyGamePDA, getRoundPDA } from "./pdas";
+
+const SOL_feedId =
+  "ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
+const priceFeedAddrSol = new PublicKey(
+  "7UVimffxr9ow1uXYxsr4LHAcV58mLzhmwaeKvJ1pjLiE"
+);
+
+const protocolAddress = new PublicKey(
+  "CVF4Gm38MrgN1rDZribjLLZvxaKxrkgHHZiZzAtfE81H"
+);
+
+const tokenAddress = new PublicKey(
+  "EL9dj31wW1sws4aXTrap8ZH3gvxAyM4LHiUm2qe8GpCM"
+);
+
+function loadKeypair(filePath: string): Keypair {
+  const resolvedPath = filePath.startsWith("~")
+    ? path.join(os.homedir(), filePath.slice(1))
+    : filePath;
+  const secretKey = JSON.parse(fs.readFileSync(resolvedPath, "utf-8"));
+  return Keypair.fromSecretKey(Uint8Array.from(secretKey));
+}
+
+export async function migrateGameInstruction(
+  program: Program<BullBearProgram>,
+  signer: PublicKey,
+  protocolAddress: PublicKey,
+  tokenAddress: PublicKey,
+  priceFeedId: string,
+  priceFeedAddr: PublicKey,
+  legacyRoundNr: number
+) {
+  // games created before the seed change are derived from the feed account
+  const legacyGameAccount = await getLegacyGamePDA(
+    program,
+    signer,
+    protocolAddress,
+    tokenAddress,
+    priceFeedAddr
+  );
+  const legacyRound = await getRoundPDA(
+    program,
+    legacyGameAccount,
+    legacyRoundNr
+  );
+
+  const gameAccount = await getGamePDA(
+    program,
+    signer,
+    protocolAddress,
+    tokenAddress,
+    priceFeedId
+  );
+
+  const instruction = program.methods
+    .migrateLegacyGame()
+    .accountsStrict({
+      gameAuthority: signer,
+      protocol: protocolAddress,
+      priceUpdate: priceFeedAddr,
+      legacyGame: legacyGameAccount,
+      legacyRound: legacyRound,
+      game: gameAccount,
+      mint: tokenAddress,
+      legacyVault: getAssociatedTokenAddressSync(
+        tokenAddress,
+        legacyGameAccount,
+        true
+      ),
+      vault: getAssociatedTokenAddressSync(tokenAddress, gameAccount, true),
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  return instruction;
+}
+
+async function main() {
+  // process arguments
+  const args = process.argv.slice(2);
+  let simulate;
+  if (args[0] == "false") {
+    simulate = false;
+  } else {
+    simulate = true;
+  }
+  // round counter of the legacy game (read it before upgrading the program)
+  const legacyRoundNr = Number(args[1] ?? 0);
+
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+  console.log("Program ID: ", program.programId.toBase58());
+
+  // authority
+  let authority: Keypair = loadKeypair("~/.config/solana/id.json");
+  console.log("Authority loaded: ", authority.publicKey.toBase58());
+
+  const transaction = new Transaction();
+  transaction.add(
+    await migrateGameInstruction(
+      program,
+      authority.publicKey,
+      protocolAddress,
+      tokenAddress,
+      SOL_feedId,
+      priceFeedAddrSol,
+      legacyRoundNr
+    )
+  );
+
+  let response;
+  if (simulate) {
+    response = await provider.simulate(transaction);
+  } else {
+    response = await provider.sendAndConfirm(transaction);
+  }
+  console.log("Game migrated: ", response);
+}
+
+main();
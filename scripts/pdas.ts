@@ -28,6 +28,27 @@ export async function getProtocolPDA(program: any, authority: any) {
 }
 
 export async function getGamePDA(
+  program: any,
+  authority: any,
+  protocolPDA: any,
+  tokenAddress: any,
+  feedId: string
+) {
+  const [pda, bump] = await PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("GAME_SEED"),
+      authority.toBuffer(),
+      protocolPDA.toBuffer(),
+      tokenAddress.toBuffer(),
+      Buffer.from(feedId, "hex"),
+    ],
+    program.programId
+  );
+
+  return pda;
+}
+
+export async function getLegacyGamePDA(
   program: any,
   authority: any,
   protocolPDA: any,
@@ -74,7 +74,9 @@ export async function startRoundInstruction(
   program: any,
   gamePDA: any,
   roundPDA: any,
-  priceFeedAccount: any
+  priceFeedAccount: any,
+  vault: any,
+  tokenAddress: any
 ) {
   const instruction = await program.methods
     .startCurrentRound()
@@ -83,6 +85,12 @@ export async function startRoundInstruction(
       game: gamePDA,
       round: roundPDA,
       priceUpdate: priceFeedAccount,
+      priceUpdateB: null,
+      mint: tokenAddress,
+      roundVault: vault,
+      gameVault: getAssociatedTokenAddressSync(tokenAddress, gamePDA, true),
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
     })
     .instruction();
@@ -114,7 +122,9 @@ async function startGame(
     program,
     gameAddress,
     round,
-    priceFeedAddrSol
+    priceFeedAddrSol,
+    vault,
+    tokenAddress
   );
 
   const transaction: Transaction = new Transaction();
@@ -158,7 +168,7 @@ async function main() {
     authority.publicKey,
     protocolAddress,
     tokenAddress,
-    priceFeedAddrSol
+    SOL_feedId
   );
 
   // start game
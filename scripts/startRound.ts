@@ -27,6 +27,7 @@ async function startRound(
   priceFeedAddrSol: any
 ) {
   const round = await getRoundPDA(program, game);
+  const token = (await program.account.game.fetch(game)).token;
 
   const tx = // start round
     await program.methods
@@ -36,6 +37,11 @@ async function startRound(
         game: game,
         round: round,
         priceUpdate: priceFeedAddrSol,
+        mint: token,
+        roundVault: splToken.getAssociatedTokenAddressSync(token, round, true),
+        gameVault: splToken.getAssociatedTokenAddressSync(token, game, true),
+        tokenProgram: splToken.TOKEN_PROGRAM_ID,
+        associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .signers([authority])
@@ -1,3 +1,9 @@
 solana-test-validator -r \
  --bpf-program 3L2Sboo2rcRmatUJLr1eu3ZqtwYu3XXKv77qBnZnRRmW "target/deploy/mock_pyth_push.so" \
- --bpf-program rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ "target/deploy/mock_pyth_pull.so"
\ No newline at end of file
+ --bpf-program rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ "target/deploy/mock_pyth_pull.so" \
+ --account 6eViiNZTCkuf1L56miipWxbyFg7A5FYz4ifGs7mMFeAS "tests/fixtures/legacy_game.json" \
+ --account FX5GgJnsPHnqXqetsNH2dfz2R35aEhxYjQr2o3ctH5C2 "tests/fixtures/legacy_vault.json" \
+ --account Fv1EED44PT2uYf1VBjK4PoCZ6qVFMtpcpQX5HYoyGhXb "tests/fixtures/legacy_mint.json" \
+ --account 6yWiSgg4mN92dUTn4jStFQCdSLB1ftntxMUTYRYNkpxz "tests/fixtures/legacy_round.json" \
+ --account EyW8iY7tpETE3a4BudC3ECS8M9Pd8NW795ipzSk1kj4C "tests/fixtures/legacy_round_vault.json" \
+ --account 2mRmcvJSonk3w5NX52DTrqey7m1VxAAWH1MZ2Gtkj3vW "tests/fixtures/legacy_bet.json"
\ No newline at end of file
@@ -1,8 +1,10 @@
 import * as anchor from "@coral-xyz/anchor";
 
 import {
+  Ed25519Program,
   Keypair,
   PublicKey,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
   SystemProgram,
   Transaction,
 } from "@solana/web3.js";
@@ -14,6 +16,8 @@ import {
   mintTo,
 } from "@solana/spl-token";
 
+import { createHash } from "crypto";
+
 import { SOL_feedId } from "./config";
 import { MockPythPull } from "../target/types/mock_pyth_pull";
 import { Wallet } from "@coral-xyz/anchor";
@@ -58,7 +62,7 @@ export async function initializeGame(
     game_authority,
     protocolPDA,
     tokenAddress,
-    priceFeed
+    SOL_feedId
   );
   const gameVaultPDA = splToken.getAssociatedTokenAddressSync(
     tokenAddress,
@@ -73,6 +77,7 @@ export async function initializeGame(
       game: gamePDA,
       mint: tokenAddress,
       vault: gameVaultPDA,
+      priceUpdate: priceFeed,
       tokenProgram: splToken.TOKEN_PROGRAM_ID,
       associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: anchor.web3.SystemProgram.programId,
@@ -91,6 +96,11 @@ export async function initializeRound(
 ) {
   const pda = await getRoundPDA(program, gamePDA);
   const vault = splToken.getAssociatedTokenAddressSync(tokenAddress, pda, true);
+  const gameVault = splToken.getAssociatedTokenAddressSync(
+    tokenAddress,
+    gamePDA,
+    true
+  );
 
   const tx = await program.methods
     .initializeNewRound()
@@ -100,6 +110,7 @@ export async function initializeRound(
       round: pda,
       mint: tokenAddress,
       vault: vault,
+      gameVault: gameVault,
       tokenProgram: splToken.TOKEN_PROGRAM_ID,
       associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: anchor.web3.SystemProgram.programId,
@@ -115,8 +126,11 @@ export async function startRound(
   signer: any,
   gamePDA: any,
   roundPDA: any,
-  priceFeed: any
+  priceFeed: any,
+  priceFeedB: any = null
 ) {
+  const tokenAddress = (await program.account.game.fetch(gamePDA)).token;
+
   const tx = // start round
     await program.methods
       .startCurrentRound()
@@ -125,6 +139,20 @@ export async function startRound(
         game: gamePDA,
         round: roundPDA,
         priceUpdate: priceFeed,
+        priceUpdateB: priceFeedB,
+        mint: tokenAddress,
+        roundVault: splToken.getAssociatedTokenAddressSync(
+          tokenAddress,
+          roundPDA,
+          true
+        ),
+        gameVault: splToken.getAssociatedTokenAddressSync(
+          tokenAddress,
+          gamePDA,
+          true
+        ),
+        tokenProgram: splToken.TOKEN_PROGRAM_ID,
+        associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .signers([signer])
@@ -141,70 +169,169 @@ export async function placeBet(
   signer: any,
   signerTokenAccount: any,
   prediction: any,
-  amount: number
+  amount: number,
+  proof: number[][] = []
+) {
+  return placeBetFor(
+    program,
+    gamePDA,
+    roundPDA,
+    roundVaultPDA,
+    tokenAddress,
+    signer,
+    signer,
+    signer,
+    signerTokenAccount,
+    prediction,
+    amount,
+    proof
+  );
+}
+
+// payer covers the rent, funder the stake, player owns the bet
+export async function placeBetFor(
+  program: any,
+  gamePDA: any,
+  roundPDA: any,
+  roundVaultPDA: any,
+  tokenAddress: any,
+  payer: any,
+  player: any,
+  funder: any,
+  funderTokenAccount: any,
+  prediction: any,
+  amount: number,
+  proof: number[][] = []
 ) {
   // generate bet PDA
-  const betPDA = await getBetPDA(program, roundPDA, signer);
+  const betPDA = await getBetPDA(program, roundPDA, player);
+  const gameVault = splToken.getAssociatedTokenAddressSync(
+    tokenAddress,
+    gamePDA,
+    true
+  );
+
+  // tokenized games mint a receipt for the bet
+  const game = await program.account.game.fetch(gamePDA);
+  let receiptMint = null;
+  let receiptAccount = null;
+  if (game.tokenizedBets) {
+    receiptMint = await getReceiptMintPDA(program, betPDA);
+    receiptAccount = splToken.getAssociatedTokenAddressSync(
+      receiptMint,
+      player.publicKey
+    );
+  }
+
+  // stats are updated once the player opened them
+  const statsAccounts = await getPlayerStatsAccounts(
+    program,
+    player.publicKey,
+    gamePDA
+  );
+
+  const seasonEntry = await getGameSeasonEntryPDA(
+    program,
+    gamePDA,
+    player.publicKey
+  );
 
+  // the player consents to bets placed on their behalf
+  const signers = [...new Set([payer, player, funder])];
   const tx_bet = await program.methods
-    .placeNewBet(prediction, new anchor.BN(amount))
+    .placeNewBet(getOutcome(prediction), new anchor.BN(amount), proof)
     .accounts({
-      player: signer.publicKey,
+      payer: payer.publicKey,
+      player: player.publicKey,
+      funder: funder.publicKey,
       game: gamePDA,
       round: roundPDA,
       bet: betPDA,
+      ...statsAccounts,
+      seasonEntry: seasonEntry,
+      referral: await getReferralPDA(program, player.publicKey),
       mint: tokenAddress,
+      receiptMint: receiptMint,
+      receiptAccount: receiptAccount,
       vault: roundVaultPDA,
-      signerVault: signerTokenAccount.address,
+      gameVault: gameVault,
+      signerVault: funderTokenAccount.address,
       tokenProgram: splToken.TOKEN_PROGRAM_ID,
       associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: anchor.web3.SystemProgram.programId,
     })
-    .signers([signer])
+    .signers(signers)
     .rpc({ commitment: "confirmed" });
   return betPDA;
 }
 
-export async function closeBetting(
+export async function cancelBet(
   program: any,
   signer: any,
   gamePDA: any,
-  roundPDA: any
+  roundPDA: any,
+  betPDA: any,
+  tokenAddress: any,
+  roundVaultPDA: any,
+  playerTokenAccount: any
 ) {
-  const tx = // start round
-    await program.methods
-      .closeBettingPhase()
-      .accounts({
-        gameAuthority: signer.publicKey,
-        game: gamePDA,
-        round: roundPDA,
-        systemProgram: anchor.web3.SystemProgram.programId,
-      })
-      .signers([signer])
-      .rpc({ commitment: "confirmed" });
+  const bet = await program.account.bet.fetch(betPDA);
+
+  const tx = await program.methods
+    .cancelPlacedBet()
+    .accounts({
+      player: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      bet: betPDA,
+      playerStats: await getPlayerStatsPDA(program, signer.publicKey, gamePDA),
+      seasonEntry: await getSeasonEntryPDA(
+        program,
+        bet.season,
+        signer.publicKey
+      ),
+      season: getBetSeason(bet),
+      mint: tokenAddress,
+      vault: roundVaultPDA,
+      gameVault: splToken.getAssociatedTokenAddressSync(
+        tokenAddress,
+        gamePDA,
+        true
+      ),
+      signerVault: playerTokenAccount.address,
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
   return tx;
 }
 
-export async function endRound(
+export async function switchBet(
   program: any,
   signer: any,
   gamePDA: any,
   roundPDA: any,
-  gameVaultPDA: any,
-  roundVaultPDA: any,
+  betPDA: any,
   tokenAddress: any,
-  priceFeed: any
+  roundVaultPDA: any,
+  prediction: any
 ) {
   const tx = await program.methods
-    .endCurrentRound()
+    .switchPlacedBet(getOutcome(prediction))
     .accounts({
-      gameAuthority: signer.publicKey,
+      player: signer.publicKey,
       game: gamePDA,
       round: roundPDA,
-      priceUpdate: priceFeed,
+      bet: betPDA,
       mint: tokenAddress,
-      round_vault: roundVaultPDA,
-      game_vault: gameVaultPDA,
+      vault: roundVaultPDA,
+      gameVault: splToken.getAssociatedTokenAddressSync(
+        tokenAddress,
+        gamePDA,
+        true
+      ),
       tokenProgram: splToken.TOKEN_PROGRAM_ID,
       associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: anchor.web3.SystemProgram.programId,
@@ -214,26 +341,40 @@ export async function endRound(
   return tx;
 }
 
-export async function claimPrize(
+export async function exitBet(
   program: any,
   signer: any,
   gamePDA: any,
   roundPDA: any,
   betPDA: any,
   tokenAddress: any,
-  roundVaultPDA: any,
-  playerTokenAccount: any
+  playerTokenAccount: any,
+  priceFeed: any
 ) {
+  const bet = await program.account.bet.fetch(betPDA);
+
   const tx = await program.methods
-    .claimUnclaimedPrize()
+    .exitPlacedBet()
     .accounts({
       player: signer.publicKey,
       game: gamePDA,
       round: roundPDA,
       bet: betPDA,
+      seasonEntry: await getSeasonEntryPDA(
+        program,
+        bet.season,
+        signer.publicKey
+      ),
+      season: getBetSeason(bet),
+      priceUpdate: priceFeed,
       mint: tokenAddress,
-      vault: roundVaultPDA,
+      gameVault: splToken.getAssociatedTokenAddressSync(
+        tokenAddress,
+        gamePDA,
+        true
+      ),
       signerVault: playerTokenAccount.address,
+      ...(await getBetPointsAccounts(program, signer, gamePDA, bet)),
       tokenProgram: splToken.TOKEN_PROGRAM_ID,
       associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: anchor.web3.SystemProgram.programId,
@@ -243,41 +384,50 @@ export async function claimPrize(
   return tx;
 }
 
-export async function withdrawFunds(
+export async function createAutoBet(
   program: any,
   signer: any,
   gamePDA: any,
-  gameVaultPDA: any,
   tokenAddress: any,
-  signerTokenAccount: any
+  playerTokenAccount: any,
+  prediction: any,
+  amount: number,
+  rounds: number
 ) {
+  const autoBetPDA = await getAutoBetPDA(program, signer.publicKey, gamePDA);
+
   const tx = await program.methods
-    .withdrawGameFunds()
+    .createGameAutoBet(getOutcome(prediction), new anchor.BN(amount), rounds)
     .accounts({
-      gameAuthority: signer.publicKey,
+      player: signer.publicKey,
       game: gamePDA,
+      autoBet: autoBetPDA,
       mint: tokenAddress,
-      vault: gameVaultPDA,
-      signerVault: signerTokenAccount.address,
+      signerVault: playerTokenAccount.address,
       tokenProgram: splToken.TOKEN_PROGRAM_ID,
-      associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: anchor.web3.SystemProgram.programId,
     })
     .signers([signer])
     .rpc({ commitment: "confirmed" });
-  return tx;
+  return autoBetPDA;
 }
-export async function updateInterval(
+
+export async function cancelAutoBet(
   program: any,
   signer: any,
   gamePDA: any,
-  interval: number
+  tokenAddress: any,
+  playerTokenAccount: any
 ) {
   const tx = await program.methods
-    .updateRoundInterval(new anchor.BN(interval))
+    .cancelGameAutoBet()
     .accounts({
-      gameAuthority: signer.publicKey,
+      player: signer.publicKey,
       game: gamePDA,
+      autoBet: await getAutoBetPDA(program, signer.publicKey, gamePDA),
+      mint: tokenAddress,
+      signerVault: playerTokenAccount.address,
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
       systemProgram: anchor.web3.SystemProgram.programId,
     })
     .signers([signer])
@@ -285,96 +435,1734 @@ export async function updateInterval(
   return tx;
 }
 
-/*//////////////////////////////////////////////////////////////
-                              PDA ACCOUNTS
-//////////////////////////////////////////////////////////////*/
-export async function getProtocolPDA(program: any, authority: any) {
-  const [pda, bump] = await PublicKey.findProgramAddressSync(
-    [
-      anchor.utils.bytes.utf8.encode("PROTOCOL_SEED"),
-      authority.publicKey.toBuffer(),
-    ],
-    program.programId
-  );
+export async function executeAutoBet(
+  program: any,
+  payer: any,
+  player: any,
+  gamePDA: any,
+  roundPDA: any,
+  roundVaultPDA: any,
+  tokenAddress: any,
+  proof: number[][] = []
+) {
+  const betPDA = await getBetPDA(program, roundPDA, { publicKey: player });
 
-  return pda;
+  const tx = await program.methods
+    .executeAutoBet(proof)
+    .accounts({
+      payer: payer.publicKey,
+      player: player,
+      game: gamePDA,
+      round: roundPDA,
+      autoBet: await getAutoBetPDA(program, player, gamePDA),
+      bet: betPDA,
+      ...(await getPlayerStatsAccounts(program, player, gamePDA)),
+      seasonEntry: await getGameSeasonEntryPDA(program, gamePDA, player),
+      referral: await getReferralPDA(program, player),
+      mint: tokenAddress,
+      vault: roundVaultPDA,
+      gameVault: splToken.getAssociatedTokenAddressSync(
+        tokenAddress,
+        gamePDA,
+        true
+      ),
+      playerVault: splToken.getAssociatedTokenAddressSync(tokenAddress, player),
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([payer])
+    .rpc({ commitment: "confirmed" });
+  return betPDA;
 }
 
-export async function getGamePDA(
+export async function initializeBetNonce(
   program: any,
-  authority: any,
-  protocolPDA: any,
-  tokenAddress: any,
-  priceFeed: any
+  payer: any,
+  player: PublicKey
 ) {
-  const [pda, bump] = await PublicKey.findProgramAddressSync(
-    [
-      anchor.utils.bytes.utf8.encode("GAME_SEED"),
-      authority.publicKey.toBuffer(),
-      protocolPDA.toBuffer(),
-      tokenAddress.toBuffer(),
-      priceFeed.toBuffer(),
-    ],
-    program.programId
-  );
+  const betNoncePDA = await getBetNoncePDA(program, player);
 
-  return pda;
+  const tx = await program.methods
+    .initializePlayerNonce()
+    .accounts({
+      payer: payer.publicKey,
+      player: player,
+      betNonce: betNoncePDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([payer])
+    .rpc({ commitment: "confirmed" });
+  return betNoncePDA;
 }
 
-export async function getRoundPDA(
+// intent is signed by the player and submitted by the relayer
+export async function placeBetSigned(
   program: any,
-  game_pda: any,
-  roundNr: number | null = null
+  relayer: any,
+  player: any,
+  gamePDA: any,
+  roundPDA: any,
+  roundVaultPDA: any,
+  tokenAddress: any,
+  prediction: any,
+  amount: number,
+  nonce: number,
+  expiry: number,
+  intentSigner: any = player,
+  proof: number[][] = []
 ) {
-  if (roundNr == null) {
-    roundNr = (await program.account.game.fetch(game_pda)).counter;
-  }
-
-  const buffer = Buffer.alloc(2);
-  buffer.writeUInt16LE(roundNr, 0);
-  const [pda, bump] = await PublicKey.findProgramAddressSync(
-    [anchor.utils.bytes.utf8.encode("ROUND_SEED"), game_pda.toBuffer(), buffer],
-    program.programId
-  );
+  const intent = {
+    player: player.publicKey,
+    game: gamePDA,
+    round: roundPDA,
+    outcome: getOutcome(prediction),
+    amount: new anchor.BN(amount),
+    nonce: new anchor.BN(nonce),
+    expiry: new anchor.BN(expiry),
+  };
+  const signatureIx = Ed25519Program.createInstructionWithPrivateKey({
+    privateKey: intentSigner.secretKey,
+    message: getBetIntentMessage(intent),
+  });
+  const betPDA = await getBetPDA(program, roundPDA, player);
 
-  return pda;
+  const tx = await program.methods
+    .placeBetSigned(intent, proof)
+    .accounts({
+      relayer: relayer.publicKey,
+      player: player.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      betNonce: await getBetNoncePDA(program, player.publicKey),
+      bet: betPDA,
+      ...(await getPlayerStatsAccounts(program, player.publicKey, gamePDA)),
+      seasonEntry: await getGameSeasonEntryPDA(
+        program,
+        gamePDA,
+        player.publicKey
+      ),
+      referral: await getReferralPDA(program, player.publicKey),
+      mint: tokenAddress,
+      vault: roundVaultPDA,
+      gameVault: splToken.getAssociatedTokenAddressSync(
+        tokenAddress,
+        gamePDA,
+        true
+      ),
+      playerVault: splToken.getAssociatedTokenAddressSync(
+        tokenAddress,
+        player.publicKey
+      ),
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .preInstructions([signatureIx])
+    .signers([relayer])
+    .rpc({ commitment: "confirmed" });
+  return betPDA;
 }
 
-export async function getBetPDA(program: any, round_pda: any, player: any) {
-  const [pda, bump] = await PublicKey.findProgramAddressSync(
-    [
-      anchor.utils.bytes.utf8.encode("BET_SEED"),
-      player.publicKey.toBuffer(),
-      round_pda.toBuffer(),
-    ],
-    program.programId
-  );
+export async function createSession(
+  program: any,
+  player: any,
+  sessionKey: PublicKey,
+  tokenAddress: any,
+  playerTokenAccount: any,
+  expiry: number,
+  spendLimit: number
+) {
+  const sessionPDA = await getSessionPDA(program, player.publicKey, sessionKey);
 
-  return pda;
+  const tx = await program.methods
+    .createPlayerSession(
+      sessionKey,
+      new anchor.BN(expiry),
+      new anchor.BN(spendLimit)
+    )
+    .accounts({
+      player: player.publicKey,
+      session: sessionPDA,
+      mint: tokenAddress,
+      signerVault: playerTokenAccount.address,
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([player])
+    .rpc({ commitment: "confirmed" });
+  return sessionPDA;
 }
 
-/*//////////////////////////////////////////////////////////////
-                                HELPERS
-//////////////////////////////////////////////////////////////*/
-
-export async function airdrop(
-  connection: any,
-  address: any,
-  amount = 10000000000000
+export async function revokeSession(
+  program: any,
+  player: any,
+  sessionKey: PublicKey,
+  tokenAddress: any,
+  playerTokenAccount: any
 ) {
-  await connection.confirmTransaction(
-    await connection.requestAirdrop(address, amount),
-    "confirmed"
-  );
+  const tx = await program.methods
+    .revokePlayerSession()
+    .accounts({
+      player: player.publicKey,
+      session: await getSessionPDA(program, player.publicKey, sessionKey),
+      mint: tokenAddress,
+      signerVault: playerTokenAccount.address,
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([player])
+    .rpc({ commitment: "confirmed" });
+  return tx;
 }
 
-export async function getToken(provider) {
-  // Create a new token mint
-  const mintAuthority = Keypair.generate();
-  await airdrop(provider.connection, mintAuthority.publicKey);
-
-  const tokenAddress = await createMint(
-    provider.connection,
+export async function placeBetWithSession(
+  program: any,
+  sessionKey: any,
+  player: PublicKey,
+  gamePDA: any,
+  roundPDA: any,
+  roundVaultPDA: any,
+  tokenAddress: any,
+  prediction: any,
+  amount: number,
+  proof: number[][] = []
+) {
+  const betPDA = await getBetPDA(program, roundPDA, { publicKey: player });
+
+  const tx = await program.methods
+    .placeNewBetWithSession(
+      getOutcome(prediction),
+      new anchor.BN(amount),
+      proof
+    )
+    .accounts({
+      sessionKey: sessionKey.publicKey,
+      player: player,
+      session: await getSessionPDA(program, player, sessionKey.publicKey),
+      game: gamePDA,
+      round: roundPDA,
+      bet: betPDA,
+      ...(await getPlayerStatsAccounts(program, player, gamePDA)),
+      seasonEntry: await getGameSeasonEntryPDA(program, gamePDA, player),
+      referral: await getReferralPDA(program, player),
+      mint: tokenAddress,
+      vault: roundVaultPDA,
+      gameVault: splToken.getAssociatedTokenAddressSync(
+        tokenAddress,
+        gamePDA,
+        true
+      ),
+      playerVault: splToken.getAssociatedTokenAddressSync(tokenAddress, player),
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([sessionKey])
+    .rpc({ commitment: "confirmed" });
+  return betPDA;
+}
+
+export async function claimPrizeWithSession(
+  program: any,
+  sessionKey: any,
+  player: PublicKey,
+  gamePDA: any,
+  roundPDA: any,
+  betPDA: any,
+  tokenAddress: any,
+  roundVaultPDA: any
+) {
+  const bet = await program.account.bet.fetch(betPDA);
+
+  const tx = await program.methods
+    .claimPrizeWithSession()
+    .accounts({
+      sessionKey: sessionKey.publicKey,
+      player: player,
+      session: await getSessionPDA(program, player, sessionKey.publicKey),
+      game: gamePDA,
+      round: roundPDA,
+      bet: betPDA,
+      playerStats: await getPlayerStatsPDA(program, player, gamePDA),
+      seasonEntry: await getSeasonEntryPDA(program, bet.season, player),
+      season: getBetSeason(bet),
+      referralRewards: await getBetReferralRewards(program, gamePDA, bet),
+      mint: tokenAddress,
+      ...(await getBetPointsAccounts(program, sessionKey, gamePDA, bet)),
+      vault: roundVaultPDA,
+      playerVault: splToken.getAssociatedTokenAddressSync(tokenAddress, player),
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([sessionKey])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function commitBet(
+  program: any,
+  gamePDA: any,
+  roundPDA: any,
+  roundVaultPDA: any,
+  tokenAddress: any,
+  signer: any,
+  signerTokenAccount: any,
+  commitment: number[],
+  amount: number,
+  proof: number[][] = []
+) {
+  const betPDA = await getBetPDA(program, roundPDA, signer);
+
+  const tx = await program.methods
+    .commitNewBet(commitment, new anchor.BN(amount), proof)
+    .accounts({
+      player: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      bet: betPDA,
+      ...(await getPlayerStatsAccounts(program, signer.publicKey, gamePDA)),
+      seasonEntry: await getGameSeasonEntryPDA(
+        program,
+        gamePDA,
+        signer.publicKey
+      ),
+      referral: await getReferralPDA(program, signer.publicKey),
+      mint: tokenAddress,
+      vault: roundVaultPDA,
+      signerVault: signerTokenAccount.address,
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return betPDA;
+}
+
+export async function revealBet(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  prediction: any,
+  salt: number[]
+) {
+  const tx = await program.methods
+    .revealCommittedBet(getOutcome(prediction), salt)
+    .accounts({
+      player: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      bet: await getBetPDA(program, roundPDA, signer),
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function initializePlayerStats(
+  program: any,
+  payer: any,
+  player: PublicKey,
+  gamePDA: any
+) {
+  const playerStatsPDA = await getPlayerStatsPDA(program, player, gamePDA);
+
+  const tx = await program.methods
+    .initializeGamePlayerStats()
+    .accounts({
+      payer: payer.publicKey,
+      player: player,
+      game: gamePDA,
+      playerStats: playerStatsPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([payer])
+    .rpc({ commitment: "confirmed" });
+  return playerStatsPDA;
+}
+
+export async function recordLostBet(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  betPDA: any
+) {
+  const bet = await program.account.bet.fetch(betPDA);
+
+  const tx = await program.methods
+    .recordLostBet()
+    .accounts({
+      signer: signer.publicKey,
+      player: bet.player,
+      game: gamePDA,
+      round: roundPDA,
+      bet: betPDA,
+      playerStats: await getPlayerStatsPDA(program, bet.player, gamePDA),
+      ...(await getBetPointsAccounts(program, signer, gamePDA, bet)),
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function createSeason(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  tokenAddress: any,
+  seasonNr: number,
+  startTime: number,
+  endTime: number,
+  feeShareBps: number,
+  payoutBps: number[],
+  challengePeriod: number
+) {
+  const seasonPDA = await getSeasonPDA(program, gamePDA, seasonNr);
+  const seasonVault = splToken.getAssociatedTokenAddressSync(
+    tokenAddress,
+    seasonPDA,
+    true
+  );
+
+  const tx = await program.methods
+    .createGameSeason(
+      seasonNr,
+      new anchor.BN(startTime),
+      new anchor.BN(endTime),
+      feeShareBps,
+      payoutBps,
+      new anchor.BN(challengePeriod)
+    )
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      season: seasonPDA,
+      mint: tokenAddress,
+      seasonVault: seasonVault,
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return [seasonPDA, seasonVault];
+}
+
+export async function joinSeason(
+  program: any,
+  payer: any,
+  player: PublicKey,
+  gamePDA: any,
+  seasonPDA: any
+) {
+  const seasonEntryPDA = await getSeasonEntryPDA(program, seasonPDA, player);
+
+  const tx = await program.methods
+    .joinGameSeason()
+    .accounts({
+      payer: payer.publicKey,
+      player: player,
+      game: gamePDA,
+      season: seasonPDA,
+      seasonEntry: seasonEntryPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([payer])
+    .rpc({ commitment: "confirmed" });
+  return seasonEntryPDA;
+}
+
+export async function commitRanking(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  seasonPDA: any,
+  tokenAddress: any,
+  ranking: PublicKey[]
+) {
+  // ranked entries are passed best first
+  const remainingAccounts = [];
+  for (const player of ranking) {
+    remainingAccounts.push({
+      pubkey: await getSeasonEntryPDA(program, seasonPDA, player),
+      isWritable: false,
+      isSigner: false,
+    });
+  }
+
+  const tx = await program.methods
+    .commitRanking()
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      season: seasonPDA,
+      mint: tokenAddress,
+      seasonVault: splToken.getAssociatedTokenAddressSync(
+        tokenAddress,
+        seasonPDA,
+        true
+      ),
+    })
+    .remainingAccounts(remainingAccounts)
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function challengeRanking(
+  program: any,
+  signer: any,
+  seasonPDA: any,
+  player: PublicKey
+) {
+  const tx = await program.methods
+    .challengeRanking()
+    .accounts({
+      challenger: signer.publicKey,
+      season: seasonPDA,
+      seasonEntry: await getSeasonEntryPDA(program, seasonPDA, player),
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function claimSeasonPrize(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  seasonPDA: any,
+  tokenAddress: any,
+  playerTokenAccount: any
+) {
+  const tx = await program.methods
+    .claimSeasonReward()
+    .accounts({
+      player: signer.publicKey,
+      game: gamePDA,
+      season: seasonPDA,
+      seasonEntry: await getSeasonEntryPDA(
+        program,
+        seasonPDA,
+        signer.publicKey
+      ),
+      mint: tokenAddress,
+      seasonVault: splToken.getAssociatedTokenAddressSync(
+        tokenAddress,
+        seasonPDA,
+        true
+      ),
+      signerVault: playerTokenAccount.address,
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function bindReferrer(
+  program: any,
+  player: any,
+  referrer: PublicKey
+) {
+  const referralPDA = await getReferralPDA(program, player.publicKey);
+
+  const tx = await program.methods
+    .bindPlayerReferrer(referrer)
+    .accounts({
+      player: player.publicKey,
+      referral: referralPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([player])
+    .rpc({ commitment: "confirmed" });
+  return referralPDA;
+}
+
+export async function initializeReferralRewards(
+  program: any,
+  payer: any,
+  referrer: PublicKey,
+  gamePDA: any
+) {
+  const referralRewardsPDA = await getReferralRewardsPDA(
+    program,
+    referrer,
+    gamePDA
+  );
+
+  const tx = await program.methods
+    .initializeReferrerRewards()
+    .accounts({
+      payer: payer.publicKey,
+      referrer: referrer,
+      game: gamePDA,
+      referralRewards: referralRewardsPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([payer])
+    .rpc({ commitment: "confirmed" });
+  return referralRewardsPDA;
+}
+
+export async function claimReferralRewards(
+  program: any,
+  referrer: any,
+  gamePDA: any,
+  tokenAddress: any,
+  referrerTokenAccount: any
+) {
+  const tx = await program.methods
+    .claimReferralRewards()
+    .accounts({
+      referrer: referrer.publicKey,
+      game: gamePDA,
+      referralRewards: await getReferralRewardsPDA(
+        program,
+        referrer.publicKey,
+        gamePDA
+      ),
+      mint: tokenAddress,
+      gameVault: splToken.getAssociatedTokenAddressSync(
+        tokenAddress,
+        gamePDA,
+        true
+      ),
+      signerVault: referrerTokenAccount.address,
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([referrer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function closeBetting(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any
+) {
+  const tx = // start round
+    await program.methods
+      .closeBettingPhase()
+      .accounts({
+        gameAuthority: signer.publicKey,
+        game: gamePDA,
+        round: roundPDA,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function endRound(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  gameVaultPDA: any,
+  roundVaultPDA: any,
+  tokenAddress: any,
+  priceFeed: any,
+  priceFeedB: any = null
+) {
+  // a running season takes its share of the settlement fee
+  let season = (await program.account.game.fetch(gamePDA)).season;
+  let seasonVault = null;
+  if (season.equals(PublicKey.default)) {
+    season = null;
+  } else {
+    seasonVault = splToken.getAssociatedTokenAddressSync(
+      tokenAddress,
+      season,
+      true
+    );
+  }
+
+  const tx = await program.methods
+    .endCurrentRound()
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      season: season,
+      seasonVault: seasonVault,
+      priceUpdate: priceFeed,
+      priceUpdateB: priceFeedB,
+      mint: tokenAddress,
+      round_vault: roundVaultPDA,
+      game_vault: gameVaultPDA,
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function claimPrize(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  betPDA: any,
+  tokenAddress: any,
+  roundVaultPDA: any,
+  playerTokenAccount: any
+) {
+  // tokenized bets are claimed with the receipt
+  let receiptMint = null;
+  let receiptAccount = null;
+  const bet = await program.account.bet.fetch(betPDA);
+  if (!bet.receiptMint.equals(PublicKey.default)) {
+    receiptMint = bet.receiptMint;
+    receiptAccount = splToken.getAssociatedTokenAddressSync(
+      receiptMint,
+      signer.publicKey
+    );
+  }
+  const playerStats = await getPlayerStatsPDA(program, bet.player, gamePDA);
+  const seasonEntry = await getSeasonEntryPDA(program, bet.season, bet.player);
+  const referralRewards = await getBetReferralRewards(program, gamePDA, bet);
+
+  const tx = await program.methods
+    .claimUnclaimedPrize()
+    .accounts({
+      player: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      bet: betPDA,
+      playerStats: playerStats,
+      seasonEntry: seasonEntry,
+      season: getBetSeason(bet),
+      referralRewards: referralRewards,
+      mint: tokenAddress,
+      ...(await getBetPointsAccounts(program, signer, gamePDA, bet)),
+      receiptMint: receiptMint,
+      receiptAccount: receiptAccount,
+      vault: roundVaultPDA,
+      signerVault: playerTokenAccount.address,
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function withdrawFunds(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  gameVaultPDA: any,
+  tokenAddress: any,
+  signerTokenAccount: any
+) {
+  const tx = await program.methods
+    .withdrawGameFunds()
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      mint: tokenAddress,
+      vault: gameVaultPDA,
+      signerVault: signerTokenAccount.address,
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+export async function updateInterval(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  interval: number
+) {
+  const tx = await program.methods
+    .updateRoundInterval(new anchor.BN(interval))
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function updateFeedAccount(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  priceFeed: any
+) {
+  const tx = await program.methods
+    .updateGameFeedAccount()
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      priceUpdate: priceFeed,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function migrateGame(
+  program: any,
+  game_authority: any,
+  protocolPDA: any,
+  legacyGamePDA: any,
+  legacyRoundPDA: any,
+  tokenAddress: any,
+  priceFeed: any
+) {
+  const gamePDA = await getGamePDA(
+    program,
+    game_authority,
+    protocolPDA,
+    tokenAddress,
+    SOL_feedId
+  );
+  const gameVaultPDA = splToken.getAssociatedTokenAddressSync(
+    tokenAddress,
+    gamePDA,
+    true
+  );
+  const legacyVault = splToken.getAssociatedTokenAddressSync(
+    tokenAddress,
+    legacyGamePDA,
+    true
+  );
+  await program.methods
+    .migrateLegacyGame()
+    .accounts({
+      gameAuthority: game_authority.publicKey,
+      protocol: protocolPDA,
+      priceUpdate: priceFeed,
+      legacyGame: legacyGamePDA,
+      legacyRound: legacyRoundPDA,
+      game: gamePDA,
+      mint: tokenAddress,
+      legacyVault: legacyVault,
+      vault: gameVaultPDA,
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([game_authority])
+    .rpc({ commitment: "confirmed" });
+
+  return [gamePDA, gameVaultPDA];
+}
+
+export async function migrateRound(program: any, payer: any, roundPDA: any) {
+  const tx = await program.methods
+    .migrateLegacyRound()
+    .accounts({
+      payer: payer.publicKey,
+      round: roundPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([payer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function migrateBet(program: any, payer: any, betPDA: any) {
+  const tx = await program.methods
+    .migrateLegacyBet()
+    .accounts({
+      payer: payer.publicKey,
+      bet: betPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([payer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function updateTwapWindow(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  twapWindow: number
+) {
+  const tx = await program.methods
+    .updateGameTwapWindow(new anchor.BN(twapWindow))
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function recordPriceSample(
+  program: any,
+  gamePDA: any,
+  roundPDA: any,
+  priceFeed: any
+) {
+  const tx = await program.methods
+    .recordRoundPriceSample()
+    .accounts({
+      game: gamePDA,
+      round: roundPDA,
+      priceUpdate: priceFeed,
+    })
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function updatePriceSource(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  priceSource: any,
+  maxEmaDeviationBps: number
+) {
+  const tx = await program.methods
+    .updateGamePriceSource(priceSource, maxEmaDeviationBps)
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function updateFlatBand(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  flatBandBps: number,
+  flatPolicy: any
+) {
+  const tx = await program.methods
+    .updateGameFlatBand(flatBandBps, flatPolicy)
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function updateMarket(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  market: any
+) {
+  const tx = await program.methods
+    .updateGameMarket(market)
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function updateBuckets(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  bucketBounds: number[]
+) {
+  const tx = await program.methods
+    .updateGameBuckets(bucketBounds)
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function updateHeadToHead(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  feedIdB: string,
+  priceFeedB: any
+) {
+  const tx = await program.methods
+    .updateGameHeadToHead(feedIdB)
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      priceUpdateB: priceFeedB,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function updateOdds(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  oddsBps: number[],
+  maxExposureBps: number
+) {
+  const tx = await program.methods
+    .updateGameOdds(oddsBps, maxExposureBps)
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function initializeLpPool(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  tokenAddress: any
+) {
+  const lpMint = await getLpMintPDA(program, gamePDA);
+
+  const tx = await program.methods
+    .initializeGameLpPool()
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      mint: tokenAddress,
+      vault: splToken.getAssociatedTokenAddressSync(
+        tokenAddress,
+        gamePDA,
+        true,
+      ),
+      lpMint: lpMint,
+      lpEscrow: splToken.getAssociatedTokenAddressSync(lpMint, gamePDA, true),
+      authorityLpAccount: splToken.getAssociatedTokenAddressSync(
+        lpMint,
+        signer.publicKey
+      ),
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return lpMint;
+}
+
+export async function initializePoints(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  tokenAddress: any
+) {
+  const pointsMint = await getPointsMintPDA(program, gamePDA);
+
+  const tx = await program.methods
+    .initializeGamePoints()
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      mint: tokenAddress,
+      pointsMint: pointsMint,
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return pointsMint;
+}
+
+export async function updatePoints(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  pointsEnabled: boolean,
+  pointsRateBps: number,
+  streakBonusBps: number
+) {
+  const tx = await program.methods
+    .updateGamePoints(pointsEnabled, pointsRateBps, streakBonusBps)
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function depositLiquidity(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  tokenAddress: any,
+  signerTokenAccount: any,
+  amount: number
+) {
+  const lpMint = await getLpMintPDA(program, gamePDA);
+  const lpAccount = await getOrCreateAssociatedTokenAccount(
+    program.provider.connection,
+    signer,
+    lpMint,
+    signer.publicKey
+  );
+
+  const game = await program.account.game.fetch(gamePDA);
+
+  const tx = await program.methods
+    .depositGameLiquidity(new anchor.BN(amount))
+    .accounts({
+      provider: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      mint: tokenAddress,
+      vault: splToken.getAssociatedTokenAddressSync(
+        tokenAddress,
+        gamePDA,
+        true,
+      ),
+      lpMint: lpMint,
+      providerVault: signerTokenAccount.address,
+      providerLpAccount: lpAccount.address,
+      authorityLpAccount: splToken.getAssociatedTokenAddressSync(
+        lpMint,
+        game.gameAuthority
+      ),
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return lpAccount.address;
+}
+
+export async function requestWithdrawal(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  shares: number
+) {
+  const lpMint = await getLpMintPDA(program, gamePDA);
+  const withdrawalPDA = await getWithdrawalPDA(program, signer, gamePDA);
+
+  const tx = await program.methods
+    .requestLiquidityWithdrawal(new anchor.BN(shares))
+    .accounts({
+      provider: signer.publicKey,
+      game: gamePDA,
+      withdrawal: withdrawalPDA,
+      lpMint: lpMint,
+      providerLpAccount: splToken.getAssociatedTokenAddressSync(
+        lpMint,
+        signer.publicKey
+      ),
+      lpEscrow: splToken.getAssociatedTokenAddressSync(lpMint, gamePDA, true),
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return withdrawalPDA;
+}
+
+export async function processWithdrawal(
+  program: any,
+  payer: any,
+  provider: any,
+  gamePDA: any,
+  roundPDA: any,
+  tokenAddress: any,
+  providerTokenAccount: any
+) {
+  const lpMint = await getLpMintPDA(program, gamePDA);
+  const withdrawalPDA = await getWithdrawalPDA(program, provider, gamePDA);
+
+  const tx = await program.methods
+    .processLiquidityWithdrawal()
+    .accounts({
+      payer: payer.publicKey,
+      provider: provider.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      withdrawal: withdrawalPDA,
+      mint: tokenAddress,
+      vault: splToken.getAssociatedTokenAddressSync(
+        tokenAddress,
+        gamePDA,
+        true,
+      ),
+      lpMint: lpMint,
+      lpEscrow: splToken.getAssociatedTokenAddressSync(lpMint, gamePDA, true),
+      providerVault: providerTokenAccount.address,
+      tokenProgram: splToken.TOKEN_PROGRAM_ID,
+      associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([payer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function updateSeedAmount(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  seedAmount: number
+) {
+  const tx = await program.methods
+    .updateGameSeedAmount(new anchor.BN(seedAmount))
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function updateCancelPenalty(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  cancelPenaltyBps: number
+) {
+  const tx = await program.methods
+    .updateGameCancelPenalty(cancelPenaltyBps)
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function updateSwitchFee(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  switchFeeBps: number
+) {
+  const tx = await program.methods
+    .updateGameSwitchFee(switchFeeBps)
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function updateSettlementFee(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  settlementFeeBps: number
+) {
+  const tx = await program.methods
+    .updateGameSettlementFee(settlementFeeBps)
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function updateReferralShare(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  referralShareBps: number
+) {
+  const tx = await program.methods
+    .updateGameReferralShare(referralShareBps)
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function updateTokenizedBets(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  tokenizedBets: boolean
+) {
+  const tx = await program.methods
+    .updateGameTokenizedBets(tokenizedBets)
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function updateEarlyExit(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  earlyExit: boolean,
+  exitSpreadBps: number
+) {
+  const tx = await program.methods
+    .updateGameEarlyExit(earlyExit, exitSpreadBps)
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function updateAllowlist(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  allowlistRoot: number[]
+) {
+  const tx = await program.methods
+    .updateGameAllowlist(allowlistRoot)
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+export async function updateCommitReveal(
+  program: any,
+  signer: any,
+  gamePDA: any,
+  roundPDA: any,
+  commitReveal: boolean,
+  revealWindow: number,
+  unrevealedPolicy: any
+) {
+  const tx = await program.methods
+    .updateGameCommitReveal(
+      commitReveal,
+      new anchor.BN(revealWindow),
+      unrevealedPolicy
+    )
+    .accounts({
+      gameAuthority: signer.publicKey,
+      game: gamePDA,
+      round: roundPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc({ commitment: "confirmed" });
+  return tx;
+}
+
+/*//////////////////////////////////////////////////////////////
+                              PDA ACCOUNTS
+//////////////////////////////////////////////////////////////*/
+export async function getProtocolPDA(program: any, authority: any) {
+  const [pda, bump] = await PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode("PROTOCOL_SEED"),
+      authority.publicKey.toBuffer(),
+    ],
+    program.programId
+  );
+
+  return pda;
+}
+
+export async function getGamePDA(
+  program: any,
+  authority: any,
+  protocolPDA: any,
+  tokenAddress: any,
+  feedId: string
+) {
+  const [pda, bump] = await PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode("GAME_SEED"),
+      authority.publicKey.toBuffer(),
+      protocolPDA.toBuffer(),
+      tokenAddress.toBuffer(),
+      getFeedIdFromHex(feedId),
+    ],
+    program.programId
+  );
+
+  return pda;
+}
+
+export async function getRoundPDA(
+  program: any,
+  game_pda: any,
+  roundNr: number | null = null
+) {
+  if (roundNr == null) {
+    roundNr = (await program.account.game.fetch(game_pda)).counter;
+  }
+
+  const buffer = Buffer.alloc(2);
+  buffer.writeUInt16LE(roundNr, 0);
+  const [pda, bump] = await PublicKey.findProgramAddressSync(
+    [anchor.utils.bytes.utf8.encode("ROUND_SEED"), game_pda.toBuffer(), buffer],
+    program.programId
+  );
+
+  return pda;
+}
+
+export async function getBetPDA(program: any, round_pda: any, player: any) {
+  const [pda, bump] = await PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode("BET_SEED"),
+      player.publicKey.toBuffer(),
+      round_pda.toBuffer(),
+    ],
+    program.programId
+  );
+
+  return pda;
+}
+
+export async function getLpMintPDA(program: any, game_pda: any) {
+  const [pda, bump] = await PublicKey.findProgramAddressSync(
+    [anchor.utils.bytes.utf8.encode("LP_MINT_SEED"), game_pda.toBuffer()],
+    program.programId
+  );
+
+  return pda;
+}
+
+export async function getPointsMintPDA(program: any, game_pda: any) {
+  const [pda, bump] = await PublicKey.findProgramAddressSync(
+    [anchor.utils.bytes.utf8.encode("POINTS_MINT_SEED"), game_pda.toBuffer()],
+    program.programId
+  );
+
+  return pda;
+}
+
+export async function getWithdrawalPDA(
+  program: any,
+  provider: any,
+  game_pda: any
+) {
+  const [pda, bump] = await PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode("WITHDRAWAL_SEED"),
+      provider.publicKey.toBuffer(),
+      game_pda.toBuffer(),
+    ],
+    program.programId
+  );
+
+  return pda;
+}
+
+export async function getReceiptMintPDA(program: any, bet_pda: any) {
+  const [pda, bump] = await PublicKey.findProgramAddressSync(
+    [anchor.utils.bytes.utf8.encode("RECEIPT_SEED"), bet_pda.toBuffer()],
+    program.programId
+  );
+
+  return pda;
+}
+
+export async function getAutoBetPDA(
+  program: any,
+  player: PublicKey,
+  game_pda: any
+) {
+  const [pda, bump] = await PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode("AUTO_BET_SEED"),
+      player.toBuffer(),
+      game_pda.toBuffer(),
+    ],
+    program.programId
+  );
+
+  return pda;
+}
+
+export async function getBetNoncePDA(program: any, player: PublicKey) {
+  const [pda, bump] = await PublicKey.findProgramAddressSync(
+    [anchor.utils.bytes.utf8.encode("BET_NONCE_SEED"), player.toBuffer()],
+    program.programId
+  );
+
+  return pda;
+}
+
+export async function getSessionPDA(
+  program: any,
+  player: PublicKey,
+  sessionKey: PublicKey
+) {
+  const [pda, bump] = await PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode("SESSION_SEED"),
+      player.toBuffer(),
+      sessionKey.toBuffer(),
+    ],
+    program.programId
+  );
+
+  return pda;
+}
+
+// stats account of the player and the pending bet settled before a new wager
+export async function getPlayerStatsAccounts(
+  program: any,
+  player: PublicKey,
+  game_pda: any
+) {
+  const playerStats = await getPlayerStatsPDA(program, player, game_pda);
+  const stats = await program.account.playerStats.fetchNullable(playerStats);
+  if (stats == null || stats.lastBet.equals(PublicKey.default)) {
+    return { playerStats, lastBet: null, lastRound: null };
+  }
+
+  const lastBet = await program.account.bet.fetch(stats.lastBet);
+  return { playerStats, lastBet: stats.lastBet, lastRound: lastBet.round };
+}
+
+export async function getPlayerStatsPDA(
+  program: any,
+  player: PublicKey,
+  game_pda: any
+) {
+  const [pda, bump] = await PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode("PLAYER_STATS_SEED"),
+      player.toBuffer(),
+      game_pda.toBuffer(),
+    ],
+    program.programId
+  );
+
+  return pda;
+}
+
+export async function getSeasonPDA(
+  program: any,
+  game_pda: any,
+  seasonNr: number
+) {
+  const buffer = Buffer.alloc(2);
+  buffer.writeUInt16LE(seasonNr, 0);
+  const [pda, bump] = await PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode("SEASON_SEED"),
+      game_pda.toBuffer(),
+      buffer,
+    ],
+    program.programId
+  );
+
+  return pda;
+}
+
+// season the stake of a bet counted against, if any
+export function getBetSeason(bet: any) {
+  return bet.season.equals(PublicKey.default) ? null : bet.season;
+}
+
+export async function getSeasonEntryPDA(
+  program: any,
+  season_pda: any,
+  player: PublicKey
+) {
+  const [pda, bump] = await PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode("SEASON_ENTRY_SEED"),
+      season_pda.toBuffer(),
+      player.toBuffer(),
+    ],
+    program.programId
+  );
+
+  return pda;
+}
+
+// entry of the player in the current season of the game, new stakes count against it once joined
+export async function getGameSeasonEntryPDA(
+  program: any,
+  game_pda: any,
+  player: PublicKey
+) {
+  const season = (await program.account.game.fetch(game_pda)).season;
+
+  return await getSeasonEntryPDA(program, season, player);
+}
+
+export async function getReferralPDA(program: any, player: PublicKey) {
+  const [pda, bump] = await PublicKey.findProgramAddressSync(
+    [anchor.utils.bytes.utf8.encode("REFERRAL_SEED"), player.toBuffer()],
+    program.programId
+  );
+
+  return pda;
+}
+
+export async function getReferralRewardsPDA(
+  program: any,
+  referrer: PublicKey,
+  game_pda: any
+) {
+  const [pda, bump] = await PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode("REFERRAL_REWARDS_SEED"),
+      referrer.toBuffer(),
+      game_pda.toBuffer(),
+    ],
+    program.programId
+  );
+
+  return pda;
+}
+
+// rewards account of the referrer of a bet, updated once opened
+export async function getBetReferralRewards(
+  program: any,
+  game_pda: any,
+  bet: any
+) {
+  return await getReferralRewardsPDA(program, bet.referrer, game_pda);
+}
+
+// points mint and points account of the bet owner, if the bet earned loyalty points
+export async function getBetPointsAccounts(
+  program: any,
+  payer: any,
+  game_pda: any,
+  bet: any
+) {
+  if (bet.points.isZero()) {
+    return { pointsMint: null, pointsAccount: null };
+  }
+  const pointsMint = (await program.account.game.fetch(game_pda)).pointsMint;
+  const pointsAccount = await splToken.getOrCreateAssociatedTokenAccount(
+    program.provider.connection,
+    payer,
+    pointsMint,
+    bet.player
+  );
+
+  return { pointsMint: pointsMint, pointsAccount: pointsAccount.address };
+}
+
+/*//////////////////////////////////////////////////////////////
+                                HELPERS
+//////////////////////////////////////////////////////////////*/
+
+export async function airdrop(
+  connection: any,
+  address: any,
+  amount = 10000000000000
+) {
+  await connection.confirmTransaction(
+    await connection.requestAirdrop(address, amount),
+    "confirmed"
+  );
+}
+
+export async function getToken(provider) {
+  // Create a new token mint
+  const mintAuthority = Keypair.generate();
+  await airdrop(provider.connection, mintAuthority.publicKey);
+
+  const tokenAddress = await createMint(
+    provider.connection,
     mintAuthority, // The payer for transaction fees
     mintAuthority.publicKey, // Mint authority
     null, // Freeze authority (optional)
@@ -410,6 +2198,123 @@ export async function getTokenAccount(
   return userTokenAccount;
 }
 
+// directional predictions map to the first outcomes, buckets are passed as index
+export function getOutcome(prediction: any): number {
+  if (typeof prediction === "number") {
+    return prediction;
+  }
+  return ["bull", "bear", "noChange"].indexOf(Object.keys(prediction)[0]);
+}
+
+// early exit pricing, mirrors the program's pricing module
+const BPS_DENOMINATOR = BigInt(10000);
+const EXIT_MOVE_SENSITIVITY = BigInt(10);
+
+export function getMoveBps(startPrice: bigint, price: bigint): bigint {
+  if (startPrice == BigInt(0)) {
+    return BigInt(0);
+  }
+  return ((price - startPrice) * BPS_DENOMINATOR) / startPrice;
+}
+
+export function getWinProbabilityBps(
+  impliedBps: bigint,
+  outcome: number,
+  moveBps: bigint
+): bigint {
+  let shift = moveBps * EXIT_MOVE_SENSITIVITY;
+  if (outcome == getOutcome({ bear: {} })) {
+    shift = -shift;
+  }
+  const probability = impliedBps + shift;
+  if (probability < BigInt(0)) {
+    return BigInt(0);
+  }
+  return probability > BPS_DENOMINATOR ? BPS_DENOMINATOR : probability;
+}
+
+export function getExitValue(
+  payout: bigint,
+  impliedBps: bigint,
+  outcome: number,
+  moveBps: bigint,
+  spreadBps: number
+): bigint {
+  const probability = getWinProbabilityBps(impliedBps, outcome, moveBps);
+  const fairValue = (payout * probability) / BPS_DENOMINATOR;
+  return (fairValue * (BPS_DENOMINATOR - BigInt(spreadBps))) / BPS_DENOMINATOR;
+}
+
+// borsh layout of the BetIntent struct signed by the player
+export function getBetIntentMessage(intent: any): Uint8Array {
+  const message = Buffer.alloc(32 * 3 + 1 + 8 * 3);
+  intent.player.toBuffer().copy(message, 0);
+  intent.game.toBuffer().copy(message, 32);
+  intent.round.toBuffer().copy(message, 64);
+  message.writeUInt8(intent.outcome, 96);
+  message.writeBigUInt64LE(BigInt(intent.amount.toString()), 97);
+  message.writeBigUInt64LE(BigInt(intent.nonce.toString()), 105);
+  message.writeBigInt64LE(BigInt(intent.expiry.toString()), 113);
+  return message;
+}
+
+// merkle tree over sha256 of the player keys with sorted pairs
+// (odd nodes move up unchanged)
+export function getAllowlistTree(players: PublicKey[]) {
+  const hashPair = (a: Buffer, b: Buffer) =>
+    createHash("sha256")
+      .update(
+        Buffer.compare(a, b) <= 0
+          ? Buffer.concat([a, b])
+          : Buffer.concat([b, a])
+      )
+      .digest();
+
+  let layer = players.map((player) =>
+    createHash("sha256").update(player.toBuffer()).digest()
+  );
+  const layers = [layer];
+  while (layer.length > 1) {
+    const next = [];
+    for (let i = 0; i < layer.length; i += 2) {
+      next.push(
+        i + 1 < layer.length ? hashPair(layer[i], layer[i + 1]) : layer[i]
+      );
+    }
+    layers.push(next);
+    layer = next;
+  }
+
+  const proofs = players.map((_, index) => {
+    const proof: number[][] = [];
+    for (const level of layers.slice(0, -1)) {
+      const sibling = index ^ 1;
+      if (sibling < level.length) {
+        proof.push(Array.from(level[sibling]));
+      }
+      index >>= 1;
+    }
+    return proof;
+  });
+
+  return { root: Array.from(layer[0]), proofs };
+}
+
+// commitment to a side, bound to the player
+export function getBetCommitment(
+  player: PublicKey,
+  prediction: any,
+  salt: number[]
+): number[] {
+  return Array.from(
+    createHash("sha256")
+      .update(player.toBuffer())
+      .update(Buffer.from([getOutcome(prediction)]))
+      .update(Buffer.from(salt))
+      .digest()
+  );
+}
+
 export function getFeedIdFromHex(hexString: string): Uint8Array {
   const buffer = Buffer.alloc(32); // Create a 32-byte buffer (zero-padded by default)
   const hexBuffer = Buffer.from(hexString, "hex"); // Convert hex string to bytes
@@ -435,7 +2340,7 @@ export async function warpToSlot(provider: any, slot: number) {
   }
 }
 
-export async function getOracle(provider) {
+export async function getOracle(provider, feedId: string = SOL_feedId) {
   const wallet = provider.wallet as Wallet;
 
   // program
@@ -446,7 +2351,7 @@ export async function getOracle(provider) {
   });
 
   const [txId, priceFeed] = await pullOracle.createOracle(
-    SOL_feedId,
+    feedId,
     100,
     100,
     -9
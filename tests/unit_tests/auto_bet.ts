@@ -0,0 +1,250 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+import { getAccount } from "@solana/spl-token";
+
+import { INTERVAL, SLOT_OFFSET, FEE } from "../config";
+import {
+  airdrop,
+  cancelAutoBet,
+  closeBetting,
+  createAutoBet,
+  endRound,
+  executeAutoBet,
+  getAutoBetPDA,
+  getOracle,
+  getToken,
+  getTokenAccount,
+  initializeGame,
+  initializeProtocol,
+  initializeRound,
+  startRound,
+  warpToSlot,
+} from "../helpers";
+
+describe("Auto Bet", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let cranker: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let playerTokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let roundPDA: PublicKey;
+  let roundVaultPDA: PublicKey;
+  let priceFeedAddr: PublicKey;
+  const amount = 10 * 10 ** 9;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+    cranker = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+    await airdrop(provider.connection, cranker.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    playerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player
+    );
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      INTERVAL,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+  });
+
+  it("should delegate the stakes of all rounds", async () => {
+    const autoBetPDA = await createAutoBet(
+      program,
+      player,
+      gamePDA,
+      tokenAddress,
+      playerTokenAccount,
+      { bull: {} },
+      amount,
+      5
+    );
+
+    const autoBet = await program.account.autoBet.fetch(autoBetPDA);
+    expect(autoBet.player.toString()).to.equal(player.publicKey.toString());
+    expect(autoBet.amount.toNumber()).to.equal(amount);
+    expect(autoBet.remaining).to.equal(5);
+
+    const tokenAccount = await getAccount(
+      provider.connection,
+      playerTokenAccount.address
+    );
+    expect(tokenAccount.delegate.toString()).to.equal(autoBetPDA.toString());
+    expect(Number(tokenAccount.delegatedAmount)).to.equal(5 * amount);
+  });
+
+  it("should let anyone place the bet for the player", async () => {
+    const autoBetPDA = await createAutoBet(
+      program,
+      player,
+      gamePDA,
+      tokenAddress,
+      playerTokenAccount,
+      { bear: {} },
+      amount,
+      5
+    );
+
+    const betPDA = await executeAutoBet(
+      program,
+      cranker,
+      player.publicKey,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress
+    );
+
+    // bet belongs to the player
+    const bet = await program.account.bet.fetch(betPDA);
+    expect(bet.player.toString()).to.equal(player.publicKey.toString());
+    expect(bet.outcome).to.equal(1);
+    expect(bet.amount.toNumber()).to.equal(amount);
+
+    const round = await program.account.round.fetch(roundPDA);
+    expect(round.totals[1].toNumber()).to.equal(amount);
+
+    // stake is taken from the allowance
+    const autoBet = await program.account.autoBet.fetch(autoBetPDA);
+    expect(autoBet.remaining).to.equal(4);
+    const tokenAccount = await getAccount(
+      provider.connection,
+      playerTokenAccount.address
+    );
+    expect(Number(tokenAccount.delegatedAmount)).to.equal(4 * amount);
+  });
+
+  it("should stop once no rounds remain", async () => {
+    await createAutoBet(
+      program,
+      player,
+      gamePDA,
+      tokenAddress,
+      playerTokenAccount,
+      { bull: {} },
+      amount,
+      1
+    );
+    await executeAutoBet(
+      program,
+      cranker,
+      player.publicKey,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress
+    );
+
+    // next round
+    await warpToSlot(provider, SLOT_OFFSET);
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+    await endRound(
+      program,
+      game_authority,
+      gamePDA,
+      roundPDA,
+      gameVaultPDA,
+      roundVaultPDA,
+      tokenAddress,
+      priceFeedAddr
+    );
+    [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    try {
+      await executeAutoBet(
+        program,
+        cranker,
+        player.publicKey,
+        gamePDA,
+        roundPDA,
+        roundVaultPDA,
+        tokenAddress
+      );
+      expect.fail("Auto-bet should be exhausted.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("AutoBetExhausted");
+    }
+  });
+
+  it("should revoke the allowance on cancel", async () => {
+    const autoBetPDA = await createAutoBet(
+      program,
+      player,
+      gamePDA,
+      tokenAddress,
+      playerTokenAccount,
+      { bull: {} },
+      amount,
+      5
+    );
+    await cancelAutoBet(
+      program,
+      player,
+      gamePDA,
+      tokenAddress,
+      playerTokenAccount
+    );
+
+    const tokenAccount = await getAccount(
+      provider.connection,
+      playerTokenAccount.address
+    );
+    expect(tokenAccount.delegate).to.be.null;
+    expect(await provider.connection.getAccountInfo(autoBetPDA)).to.be.null;
+  });
+});
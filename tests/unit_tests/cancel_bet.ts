@@ -0,0 +1,231 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+import { getAccount } from "@solana/spl-token";
+
+import { INTERVAL, SLOT_OFFSET, FEE } from "../config";
+import {
+  airdrop,
+  cancelBet,
+  closeBetting,
+  createSeason,
+  getOracle,
+  getPlayerStatsPDA,
+  getRoundPDA,
+  getSeasonEntryPDA,
+  getToken,
+  getTokenAccount,
+  initializeGame,
+  initializePlayerStats,
+  initializeProtocol,
+  initializeRound,
+  joinSeason,
+  placeBet,
+  startRound,
+  updateCancelPenalty,
+  warpToSlot,
+} from "../helpers";
+
+describe("Cancel Bet", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let playerTokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let roundPDA: PublicKey;
+  let roundVaultPDA: PublicKey;
+  let betPDA: PublicKey;
+  let priceFeedAddr: PublicKey;
+  const amount = 100 * 10 ** 9;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    playerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player
+    );
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      INTERVAL,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    // 5% penalty
+    const pda = await getRoundPDA(program, gamePDA);
+    await updateCancelPenalty(program, game_authority, gamePDA, pda, 500);
+
+    [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    betPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      { bull: {} },
+      amount
+    );
+  });
+
+  it("should refund the stake minus the penalty", async () => {
+    const initialBalance = (
+      await getAccount(provider.connection, playerTokenAccount.address)
+    ).amount;
+    await cancelBet(
+      program,
+      player,
+      gamePDA,
+      roundPDA,
+      betPDA,
+      tokenAddress,
+      roundVaultPDA,
+      playerTokenAccount
+    );
+    const finalBalance = (
+      await getAccount(provider.connection, playerTokenAccount.address)
+    ).amount;
+    expect(Number(finalBalance - initialBalance)).to.equal(95 * 10 ** 9);
+
+    // penalty goes to the game vault
+    const houseBalance = (await getAccount(provider.connection, gameVaultPDA))
+      .amount;
+    expect(Number(houseBalance)).to.equal(5 * 10 ** 9);
+
+    // pool and bet are updated
+    const round = await program.account.round.fetch(roundPDA);
+    expect(round.totals[0].toNumber()).to.equal(0);
+    expect(round.numBets).to.equal(0);
+    expect(await provider.connection.getAccountInfo(betPDA)).to.be.null;
+  });
+
+  it("should undo the wager in the player stats and the season", async () => {
+    await cancelBet(
+      program,
+      player,
+      gamePDA,
+      roundPDA,
+      betPDA,
+      tokenAddress,
+      roundVaultPDA,
+      playerTokenAccount
+    );
+
+    // stake the bet again with stats opened and the season joined
+    await initializePlayerStats(program, player, player.publicKey, gamePDA);
+    const now = Math.floor(Date.now() / 1000);
+    const [seasonPDA] = await createSeason(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress,
+      0,
+      now - 60,
+      now + 600,
+      5000,
+      [10000],
+      0
+    );
+    await joinSeason(program, player, player.publicKey, gamePDA, seasonPDA);
+    betPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      { bull: {} },
+      amount
+    );
+    await cancelBet(
+      program,
+      player,
+      gamePDA,
+      roundPDA,
+      betPDA,
+      tokenAddress,
+      roundVaultPDA,
+      playerTokenAccount
+    );
+
+    const stats = await program.account.playerStats.fetch(
+      await getPlayerStatsPDA(program, player.publicKey, gamePDA)
+    );
+    expect(stats.totalWagered.toNumber()).to.equal(0);
+
+    // only the penalty counts against the season
+    const entry = await program.account.seasonEntry.fetch(
+      await getSeasonEntryPDA(program, seasonPDA, player.publicKey)
+    );
+    expect(entry.netWinnings.toNumber()).to.equal(-5 * 10 ** 9);
+  });
+
+  it("should not allow cancelling after betting closed", async () => {
+    await warpToSlot(provider, SLOT_OFFSET);
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+
+    try {
+      await cancelBet(
+        program,
+        player,
+        gamePDA,
+        roundPDA,
+        betPDA,
+        tokenAddress,
+        roundVaultPDA,
+        playerTokenAccount
+      );
+      expect.fail("Bet should not be cancelled.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("BettingIsClosed");
+    }
+  });
+});
@@ -0,0 +1,228 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+import { getAccount } from "@solana/spl-token";
+
+import { FEE } from "../config";
+import {
+  airdrop,
+  closeBetting,
+  commitBet,
+  endRound,
+  getBetCommitment,
+  getOracle,
+  getRoundPDA,
+  getToken,
+  getTokenAccount,
+  initializeGame,
+  initializeProtocol,
+  initializeRound,
+  placeBet,
+  revealBet,
+  setOraclePrice,
+  startRound,
+  updateCommitReveal,
+} from "../helpers";
+import { pullOracleClient } from "../mock_oracle";
+
+describe("Commit Reveal", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let player2: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let playerTokenAccount: splToken.Account;
+  let player2TokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let roundPDA: PublicKey;
+  let roundVaultPDA: PublicKey;
+  let priceFeedAddr: PublicKey;
+  let pullOracle: pullOracleClient;
+  const amount = 100 * 10 ** 9;
+  const salt = Array.from(anchor.web3.Keypair.generate().publicKey.toBytes());
+  // long enough to reveal after betting closed
+  const interval = 6;
+  const revealWindow = 2;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+    player2 = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+    await airdrop(provider.connection, player2.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    playerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player
+    );
+
+    player2TokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player2
+    );
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+    pullOracle = oracle.pullOracle;
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 60);
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      interval,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    // unrevealed stakes go to the house
+    const pda = await getRoundPDA(program, gamePDA);
+    await updateCommitReveal(
+      program,
+      game_authority,
+      gamePDA,
+      pda,
+      true,
+      revealWindow,
+      { house: {} }
+    );
+
+    [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    await commitBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      getBetCommitment(player.publicKey, { bull: {} }, salt),
+      amount
+    );
+  });
+
+  it("should hide the side until it is revealed", async () => {
+    let round = await program.account.round.fetch(roundPDA);
+    expect(round.committed.toNumber()).to.equal(amount);
+    expect(round.totals[0].toNumber()).to.equal(0);
+
+    // wait for the betting phase to end
+    await new Promise((resolve) => setTimeout(resolve, (interval / 2) * 1000));
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+    await revealBet(program, player, gamePDA, roundPDA, { bull: {} }, salt);
+
+    round = await program.account.round.fetch(roundPDA);
+    expect(round.committed.toNumber()).to.equal(0);
+    expect(round.totals[0].toNumber()).to.equal(amount);
+  });
+
+  it("should not accept a different side", async () => {
+    await new Promise((resolve) => setTimeout(resolve, (interval / 2) * 1000));
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+
+    try {
+      await revealBet(program, player, gamePDA, roundPDA, { bear: {} }, salt);
+      expect.fail("Reveal should not be accepted.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("InvalidReveal");
+    }
+  });
+
+  it("should not accept open bets", async () => {
+    try {
+      await placeBet(
+        program,
+        gamePDA,
+        roundPDA,
+        roundVaultPDA,
+        tokenAddress,
+        player2,
+        player2TokenAccount,
+        { bear: {} },
+        amount
+      );
+      expect.fail("Bet should be committed.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("CommitRevealRequired");
+    }
+  });
+
+  it("should forfeit unrevealed stakes to the house", async () => {
+    await commitBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player2,
+      player2TokenAccount,
+      getBetCommitment(player2.publicKey, { bear: {} }, salt),
+      amount
+    );
+
+    // only the first player reveals
+    await new Promise((resolve) => setTimeout(resolve, (interval / 2) * 1000));
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+    await revealBet(program, player, gamePDA, roundPDA, { bull: {} }, salt);
+
+    // wait for the round to end
+    await new Promise((resolve) => setTimeout(resolve, (interval / 2) * 1000));
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 70);
+    await endRound(
+      program,
+      game_authority,
+      gamePDA,
+      roundPDA,
+      gameVaultPDA,
+      roundVaultPDA,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    const houseBalance = (await getAccount(provider.connection, gameVaultPDA))
+      .amount;
+    expect(Number(houseBalance)).to.equal(amount);
+    const vaultBalance = (await getAccount(provider.connection, roundVaultPDA))
+      .amount;
+    expect(Number(vaultBalance)).to.equal(amount);
+  });
+});
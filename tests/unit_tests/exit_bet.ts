@@ -0,0 +1,241 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+import { getAccount } from "@solana/spl-token";
+
+import { FEE } from "../config";
+import {
+  airdrop,
+  closeBetting,
+  exitBet,
+  getExitValue,
+  getMoveBps,
+  getOracle,
+  getOutcome,
+  getRoundPDA,
+  getToken,
+  getTokenAccount,
+  initializeGame,
+  initializePoints,
+  initializeProtocol,
+  initializeRound,
+  placeBet,
+  setOraclePrice,
+  startRound,
+  updateEarlyExit,
+  updatePoints,
+} from "../helpers";
+import { pullOracleClient } from "../mock_oracle";
+
+describe("Exit Bet", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let player2: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let playerTokenAccount: splToken.Account;
+  let player2TokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let roundPDA: PublicKey;
+  let betPDA: PublicKey;
+  let pointsMint: PublicKey;
+  let priceFeedAddr: PublicKey;
+  let pullOracle: pullOracleClient;
+  const amount = 100 * 10 ** 9;
+  const spreadBps = 200;
+  const pointsRateBps = 5000;
+  // long enough to exit after betting closed
+  const interval = 10;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+    player2 = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+    await airdrop(provider.connection, player2.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    playerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player
+    );
+
+    player2TokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player2
+    );
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+    pullOracle = oracle.pullOracle;
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 60);
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      interval,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    // fund house
+    await splToken.mintTo(
+      provider.connection,
+      token.authority,
+      tokenAddress,
+      gameVaultPDA,
+      token.authority,
+      1000 * 10 ** 9
+    );
+
+    const pda = await getRoundPDA(program, gamePDA);
+    await updateEarlyExit(
+      program,
+      game_authority,
+      gamePDA,
+      pda,
+      true,
+      spreadBps
+    );
+
+    pointsMint = await initializePoints(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await updatePoints(program, game_authority, gamePDA, true, pointsRateBps, 0);
+
+    let roundVaultPDA: PublicKey;
+    [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    betPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      { bull: {} },
+      amount
+    );
+    await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player2,
+      player2TokenAccount,
+      { bear: {} },
+      amount
+    );
+  });
+
+  it("should not allow exits while betting is open", async () => {
+    try {
+      await exitBet(
+        program,
+        player,
+        gamePDA,
+        roundPDA,
+        betPDA,
+        tokenAddress,
+        playerTokenAccount,
+        priceFeedAddr
+      );
+      expect.fail("Open bets should be cancelled instead.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("BettingNeedsToBeClosed");
+    }
+  });
+
+  it("should sell the position to the house at the exit price", async () => {
+    // wait for the betting phase to end
+    await new Promise((resolve) => setTimeout(resolve, (interval / 2) * 1000));
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 61);
+
+    const round = await program.account.round.fetch(roundPDA);
+    const price = BigInt(61 * 10 ** 9);
+
+    // equal pools: winning pays twice the stake at 50% implied probability
+    const expected = getExitValue(
+      BigInt(2 * amount),
+      BigInt(5000),
+      getOutcome({ bull: {} }),
+      getMoveBps(BigInt(round.startPrice.toString()), price),
+      spreadBps
+    );
+
+    const initialBalance = (
+      await getAccount(provider.connection, playerTokenAccount.address)
+    ).amount;
+    await exitBet(
+      program,
+      player,
+      gamePDA,
+      roundPDA,
+      betPDA,
+      tokenAddress,
+      playerTokenAccount,
+      priceFeedAddr
+    );
+    const finalBalance = (
+      await getAccount(provider.connection, playerTokenAccount.address)
+    ).amount;
+    expect(finalBalance - initialBalance).to.equal(expected);
+
+    const bet = await program.account.bet.fetch(betPDA);
+    expect(bet.status).to.deep.equal({ exited: {} });
+    const exited = (await program.account.round.fetch(roundPDA)).exited;
+    expect(exited[0].toNumber()).to.equal(amount);
+
+    // exited bets never settle, their points are minted on exit
+    const pointsAccount = splToken.getAssociatedTokenAddressSync(
+      pointsMint,
+      player.publicKey
+    );
+    const points = (await getAccount(provider.connection, pointsAccount))
+      .amount;
+    expect(points).to.equal(BigInt((amount * pointsRateBps) / 10 ** 4));
+  });
+});
@@ -17,6 +17,7 @@ import {
   setOraclePrice,
 } from "../helpers";
 import { pullOracleClient } from "../mock_oracle";
+import { confirmTransaction } from "@solana-developers/helpers";
 
 describe("Intialize Game", () => {
   // provider
@@ -146,4 +147,29 @@ describe("Intialize Game", () => {
       "Protocol balance mismatch"
     );
   });
+
+  it("should reject a price account that does not match the feed id", async () => {
+    // create a price account for a different feed
+    const [txId, wifFeedAddr] = await pullOracle.createOracle(
+      WIF_feedId,
+      100,
+      -9
+    );
+    await confirmTransaction(provider.connection, txId);
+
+    try {
+      await initializeGame(
+        program,
+        game_authority,
+        protocolPDA,
+        roundInterval,
+        tokenAddress,
+        wifFeedAddr
+      );
+      expect.fail("Game should not be initialized with mismatched feed.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("FeedIdMismatch");
+    }
+  });
 });
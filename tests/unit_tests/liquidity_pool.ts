@@ -0,0 +1,268 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+import { getAccount, getMint } from "@solana/spl-token";
+
+import { INTERVAL, FEE } from "../config";
+import {
+  airdrop,
+  depositLiquidity,
+  getOracle,
+  getRoundPDA,
+  getToken,
+  getTokenAccount,
+  initializeGame,
+  initializeLpPool,
+  initializeProtocol,
+  initializeRound,
+  processWithdrawal,
+  requestWithdrawal,
+  startRound,
+  withdrawFunds,
+} from "../helpers";
+
+describe("Liquidity Pool", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let lp: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let tokenAuthority: Keypair;
+  let lpTokenAccount: splToken.Account;
+  let authorityTokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let lpMint: PublicKey;
+  let priceFeedAddr: PublicKey;
+  const houseFunds = 1000 * 10 ** 9;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    lp = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, lp.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+    tokenAuthority = token.authority;
+
+    lpTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      lp
+    );
+
+    authorityTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      game_authority
+    );
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      INTERVAL,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    // fund house and open the pool
+    await splToken.mintTo(
+      provider.connection,
+      tokenAuthority,
+      tokenAddress,
+      gameVaultPDA,
+      tokenAuthority,
+      houseFunds
+    );
+    const pda = await getRoundPDA(program, gamePDA);
+    lpMint = await initializeLpPool(
+      program,
+      game_authority,
+      gamePDA,
+      pda,
+      tokenAddress
+    );
+  });
+
+  it("should mint shares for existing house funds", async () => {
+    const lpAccount = splToken.getAssociatedTokenAddressSync(
+      lpMint,
+      game_authority.publicKey
+    );
+    const shares = (await getAccount(provider.connection, lpAccount)).amount;
+    expect(Number(shares)).to.equal(houseFunds);
+
+    const game = await program.account.game.fetch(gamePDA);
+    expect(game.lpMint.toBase58()).to.equal(lpMint.toBase58());
+  });
+
+  it("should not allow the authority to withdraw pool funds", async () => {
+    try {
+      await withdrawFunds(
+        program,
+        game_authority,
+        gamePDA,
+        gameVaultPDA,
+        tokenAddress,
+        authorityTokenAccount
+      );
+      expect.fail("Withdrawal should be rejected.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("LiquidityPoolEnabled");
+    }
+  });
+
+  it("should return deposits through queued withdrawals", async () => {
+    const amount = 500 * 10 ** 9;
+    const pda = await getRoundPDA(program, gamePDA);
+    const lpAccount = await depositLiquidity(
+      program,
+      lp,
+      gamePDA,
+      pda,
+      tokenAddress,
+      lpTokenAccount,
+      amount
+    );
+    const shares = (await getAccount(provider.connection, lpAccount)).amount;
+    expect(Number(shares)).to.equal(amount);
+
+    await requestWithdrawal(program, lp, gamePDA, Number(shares));
+    const game = await program.account.game.fetch(gamePDA);
+    expect(game.queuedShares.toNumber()).to.equal(amount);
+
+    const initialBalance = (
+      await getAccount(provider.connection, lpTokenAccount.address)
+    ).amount;
+    await processWithdrawal(
+      program,
+      game_authority,
+      lp,
+      gamePDA,
+      pda,
+      tokenAddress,
+      lpTokenAccount
+    );
+    const finalBalance = (
+      await getAccount(provider.connection, lpTokenAccount.address)
+    ).amount;
+    expect(Number(finalBalance - initialBalance)).to.equal(amount);
+
+    const supply = (await getMint(provider.connection, lpMint)).supply;
+    expect(Number(supply)).to.equal(houseFunds);
+  });
+
+  it("should not process withdrawals during a round", async () => {
+    const pda = await getRoundPDA(program, gamePDA);
+    await depositLiquidity(
+      program,
+      lp,
+      gamePDA,
+      pda,
+      tokenAddress,
+      lpTokenAccount,
+      100 * 10 ** 9
+    );
+    await requestWithdrawal(program, lp, gamePDA, 100 * 10 ** 9);
+
+    const [roundPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    try {
+      await processWithdrawal(
+        program,
+        game_authority,
+        lp,
+        gamePDA,
+        roundPDA,
+        tokenAddress,
+        lpTokenAccount
+      );
+      expect.fail("Withdrawal should be queued until the round ends.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("RoundInProgress");
+    }
+  });
+
+  it("should credit funds of an empty pool to the game authority", async () => {
+    // authority exits the pool completely
+    const pda = await getRoundPDA(program, gamePDA);
+    await requestWithdrawal(program, game_authority, gamePDA, houseFunds);
+    await processWithdrawal(
+      program,
+      game_authority,
+      game_authority,
+      gamePDA,
+      pda,
+      tokenAddress,
+      authorityTokenAccount
+    );
+
+    // funds accrue to the empty pool
+    const accrued = 200 * 10 ** 9;
+    await splToken.mintTo(
+      provider.connection,
+      tokenAuthority,
+      tokenAddress,
+      gameVaultPDA,
+      tokenAuthority,
+      accrued
+    );
+
+    const amount = 100 * 10 ** 9;
+    const lpAccount = await depositLiquidity(
+      program,
+      lp,
+      gamePDA,
+      pda,
+      tokenAddress,
+      lpTokenAccount,
+      amount
+    );
+    const shares = (await getAccount(provider.connection, lpAccount)).amount;
+    expect(Number(shares)).to.equal(amount);
+
+    const authorityLpAccount = splToken.getAssociatedTokenAddressSync(
+      lpMint,
+      game_authority.publicKey
+    );
+    const authorityShares = (
+      await getAccount(provider.connection, authorityLpAccount)
+    ).amount;
+    expect(Number(authorityShares)).to.equal(accrued);
+  });
+});
@@ -0,0 +1,361 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+import { getAccount } from "@solana/spl-token";
+
+import { INTERVAL, SLOT_OFFSET, FEE } from "../config";
+import { pullOracleClient } from "../mock_oracle";
+import {
+  airdrop,
+  cancelBet,
+  claimPrize,
+  closeBetting,
+  endRound,
+  getOracle,
+  getToken,
+  getTokenAccount,
+  initializeGame,
+  initializePlayerStats,
+  initializePoints,
+  initializeProtocol,
+  initializeRound,
+  placeBet,
+  recordLostBet,
+  setOraclePrice,
+  startRound,
+  updatePoints,
+  warpToSlot,
+} from "../helpers";
+
+describe("Loyalty Points", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let player2: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let playerTokenAccount: splToken.Account;
+  let player2TokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let pointsMint: PublicKey;
+  let priceFeedAddr: PublicKey;
+  let pullOracle: pullOracleClient;
+  const amount = 100 * 10 ** 9;
+  const pointsRateBps = 5000;
+  const streakBonusBps = 1000;
+
+  async function hasPointsAccount(owner: PublicKey) {
+    const pointsAccount = splToken.getAssociatedTokenAddressSync(
+      pointsMint,
+      owner
+    );
+    return (await provider.connection.getAccountInfo(pointsAccount)) != null;
+  }
+
+  async function getPoints(owner: PublicKey) {
+    const pointsAccount = splToken.getAssociatedTokenAddressSync(
+      pointsMint,
+      owner
+    );
+    return (await getAccount(provider.connection, pointsAccount)).amount;
+  }
+
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+    player2 = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+    await airdrop(provider.connection, player2.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    playerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player
+    );
+
+    player2TokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player2
+    );
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+    pullOracle = oracle.pullOracle;
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 60);
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      INTERVAL,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    pointsMint = await initializePoints(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await updatePoints(
+      program,
+      game_authority,
+      gamePDA,
+      true,
+      pointsRateBps,
+      streakBonusBps
+    );
+  });
+
+  it("should mint points proportional to the stake once settled", async () => {
+    const [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    const betPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      { bull: {} },
+      amount
+    );
+    const loserBetPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player2,
+      player2TokenAccount,
+      { bear: {} },
+      amount
+    );
+
+    // points are only recorded on the bet until it settles
+    const points = (amount * pointsRateBps) / 10 ** 4;
+    const bet = await program.account.bet.fetch(betPDA);
+    expect(bet.points.toNumber()).to.equal(points);
+    expect(await hasPointsAccount(player.publicKey)).to.be.false;
+
+    // bull wins
+    await warpToSlot(provider, SLOT_OFFSET);
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 90);
+    await endRound(
+      program,
+      game_authority,
+      gamePDA,
+      roundPDA,
+      gameVaultPDA,
+      roundVaultPDA,
+      tokenAddress,
+      priceFeedAddr
+    );
+    await claimPrize(
+      program,
+      player,
+      gamePDA,
+      roundPDA,
+      betPDA,
+      tokenAddress,
+      roundVaultPDA,
+      playerTokenAccount
+    );
+    await recordLostBet(program, game_authority, gamePDA, roundPDA, loserBetPDA);
+
+    expect(await getPoints(player.publicKey)).to.equal(BigInt(points));
+    expect(await getPoints(player2.publicKey)).to.equal(BigInt(points));
+  });
+
+  it("should not mint points for cancelled bets", async () => {
+    const [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    const betPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      { bull: {} },
+      amount
+    );
+    await cancelBet(
+      program,
+      player,
+      gamePDA,
+      roundPDA,
+      betPDA,
+      tokenAddress,
+      roundVaultPDA,
+      playerTokenAccount
+    );
+
+    expect(await hasPointsAccount(player.publicKey)).to.be.false;
+  });
+
+  it("should raise the rate for a winning streak", async () => {
+    await initializePlayerStats(program, player, player.publicKey, gamePDA);
+
+    // win the first round
+    let [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+    const betPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      { bear: {} },
+      amount
+    );
+    await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player2,
+      player2TokenAccount,
+      { bull: {} },
+      amount
+    );
+    await warpToSlot(provider, SLOT_OFFSET);
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 30);
+    await endRound(
+      program,
+      game_authority,
+      gamePDA,
+      roundPDA,
+      gameVaultPDA,
+      roundVaultPDA,
+      tokenAddress,
+      priceFeedAddr
+    );
+    await claimPrize(
+      program,
+      player,
+      gamePDA,
+      roundPDA,
+      betPDA,
+      tokenAddress,
+      roundVaultPDA,
+      playerTokenAccount
+    );
+
+    // next bet earns the streak bonus
+    [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+    const nextBetPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      { bull: {} },
+      amount
+    );
+
+    const multiplierBps = 10 ** 4 + streakBonusBps;
+    const nextBet = await program.account.bet.fetch(nextBetPDA);
+    expect(nextBet.points.toNumber()).to.equal(
+      (amount * pointsRateBps * multiplierBps) / 10 ** 8
+    );
+  });
+
+  it("should not mint points while disabled", async () => {
+    await updatePoints(program, game_authority, gamePDA, false, 0, 0);
+
+    const [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+    const betPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      { bull: {} },
+      amount
+    );
+
+    const bet = await program.account.bet.fetch(betPDA);
+    expect(bet.points.toNumber()).to.equal(0);
+  });
+
+  it("should not enable points without a rate", async () => {
+    try {
+      await updatePoints(program, game_authority, gamePDA, true, 0, 0);
+      expect.fail("Points should need a rate.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("InvalidPointsRate");
+    }
+  });
+});
@@ -0,0 +1,136 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+
+import { FEE } from "../config";
+import {
+  airdrop,
+  claimPrize,
+  getBetPDA,
+  getOracle,
+  getRoundPDA,
+  initializeProtocol,
+  migrateBet,
+  migrateGame,
+  migrateRound,
+} from "../helpers";
+import legacyAuthoritySecret from "../fixtures/legacy_authority.json";
+import legacyPlayerSecret from "../fixtures/legacy_player.json";
+
+// accounts loaded into the validator from tests/fixtures (see Anchor.toml)
+const LEGACY_GAME = new PublicKey(
+  "6eViiNZTCkuf1L56miipWxbyFg7A5FYz4ifGs7mMFeAS"
+);
+const LEGACY_MINT = new PublicKey(
+  "Fv1EED44PT2uYf1VBjK4PoCZ6qVFMtpcpQX5HYoyGhXb"
+);
+const LEGACY_COUNTER = 2;
+const LEGACY_VAULT_AMOUNT = BigInt(50 * 10 ** 9);
+// ended bull round 1 with a winning bull bet of 100 (pool of 150)
+const LEGACY_ROUND_NR = 1;
+const LEGACY_PRIZE = BigInt(150 * 10 ** 9);
+
+describe("Migrate Game", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let player: Keypair;
+  let protocolPDA: PublicKey;
+  let priceFeedAddr: PublicKey;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  before("Setup", async () => {
+    // legacy game was created by this authority
+    authority = Keypair.fromSecretKey(Uint8Array.from(legacyAuthoritySecret));
+    await airdrop(provider.connection, authority.publicKey);
+    player = Keypair.fromSecretKey(Uint8Array.from(legacyPlayerSecret));
+    await airdrop(provider.connection, player.publicKey);
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+  });
+
+  it("should migrate a game created before feed id seeding", async () => {
+    const legacyRoundPDA = await getRoundPDA(
+      program,
+      LEGACY_GAME,
+      LEGACY_COUNTER
+    );
+    [gamePDA, gameVaultPDA] = await migrateGame(
+      program,
+      authority,
+      protocolPDA,
+      LEGACY_GAME,
+      legacyRoundPDA,
+      LEGACY_MINT,
+      priceFeedAddr
+    );
+
+    // check migrated game state
+    const game = await program.account.game.fetch(gamePDA);
+    expect(game.counter).to.equal(LEGACY_COUNTER);
+    expect(game.legacyGame.toString()).to.equal(LEGACY_GAME.toString());
+    expect(game.feedAccount.toString()).to.equal(priceFeedAddr.toString());
+
+    // check funds moved to the new vault
+    const vault = await splToken.getAccount(provider.connection, gameVaultPDA);
+    expect(vault.amount).to.equal(LEGACY_VAULT_AMOUNT);
+
+    // check legacy game is closed
+    const legacyGame = await provider.connection.getAccountInfo(LEGACY_GAME);
+    expect(legacyGame).to.be.null;
+  });
+
+  it("should keep prizes of migrated legacy rounds claimable", async () => {
+    const roundPDA = await getRoundPDA(program, LEGACY_GAME, LEGACY_ROUND_NR);
+    const betPDA = await getBetPDA(program, roundPDA, player);
+    await migrateRound(program, player, roundPDA);
+    await migrateBet(program, player, betPDA);
+
+    // check accounts are readable in the current layout
+    const round = await program.account.round.fetch(roundPDA);
+    expect(round.game.toString()).to.equal(LEGACY_GAME.toString());
+    const bet = await program.account.bet.fetch(betPDA);
+    expect(bet.claimed).to.equal(false);
+
+    // claim through the migrated game
+    const playerTokenAccount = await splToken.getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      player,
+      LEGACY_MINT,
+      player.publicKey
+    );
+    const roundVaultPDA = splToken.getAssociatedTokenAddressSync(
+      LEGACY_MINT,
+      roundPDA,
+      true
+    );
+    await claimPrize(
+      program,
+      player,
+      gamePDA,
+      roundPDA,
+      betPDA,
+      LEGACY_MINT,
+      roundVaultPDA,
+      playerTokenAccount
+    );
+
+    const balance = (
+      await splToken.getAccount(provider.connection, playerTokenAccount.address)
+    ).amount;
+    expect(balance).to.equal(LEGACY_PRIZE);
+  });
+});
@@ -10,12 +10,14 @@ import {
   airdrop,
   closeBetting,
   getOracle,
+  getOutcome,
   getToken,
   getTokenAccount,
   initializeGame,
   initializeProtocol,
   initializeRound,
   placeBet,
+  placeBetFor,
   setOraclePrice,
   startRound,
   warpToSlot,
@@ -148,11 +150,11 @@ describe("Place Bet", () => {
       amount
     );
 
-    const betPrediction = (await program.account.bet.fetch(betPDA)).prediction;
-    expect(Object.keys(betPrediction)[0].toString()).to.equal("bear");
+    const betOutcome = (await program.account.bet.fetch(betPDA)).outcome;
+    expect(betOutcome).to.equal(getOutcome(prediction));
 
     const totalBetsDown = (await program.account.round.fetch(roundPDA))
-      .totalDown;
+      .totals[getOutcome(prediction)];
     expect(totalBetsDown.toString()).to.equal(new anchor.BN(amount).toString());
 
     const playerNewBalance = (
@@ -165,6 +167,66 @@ describe("Place Bet", () => {
     expect(playerNewBalance).to.equal(playerInitialBalance - BigInt(amount));
   });
 
+  it("should allow a sponsor to place a bet for another wallet", async () => {
+    const beneficiary = anchor.web3.Keypair.generate();
+    const sponsorInitialBalance = (
+      await getAccount(provider.connection, gameAuthorityTokenAccount.address)
+    ).amount;
+
+    // sponsor pays rent and stake
+    const amount = 100 * 10 ** 9;
+    const betPDA = await placeBetFor(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      game_authority,
+      beneficiary,
+      game_authority,
+      gameAuthorityTokenAccount,
+      { bull: {} },
+      amount
+    );
+
+    // bet belongs to the beneficiary
+    const bet = await program.account.bet.fetch(betPDA);
+    expect(bet.player.toString()).to.equal(beneficiary.publicKey.toString());
+
+    const sponsorNewBalance = (
+      await getAccount(provider.connection, gameAuthorityTokenAccount.address)
+    ).amount;
+    expect(sponsorNewBalance).to.equal(sponsorInitialBalance - BigInt(amount));
+  });
+
+  it("should allow a relayer to pay the rent of a player funded bet", async () => {
+    const playerLamports = await provider.connection.getBalance(
+      player.publicKey
+    );
+
+    // relayer pays rent, player pays stake
+    const amount = 100 * 10 ** 9;
+    const betPDA = await placeBetFor(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      game_authority,
+      player,
+      player,
+      playerTokenAccount,
+      { bear: {} },
+      amount
+    );
+
+    const bet = await program.account.bet.fetch(betPDA);
+    expect(bet.player.toString()).to.equal(player.publicKey.toString());
+    expect(await provider.connection.getBalance(player.publicKey)).to.equal(
+      playerLamports
+    );
+  });
+
   it("should not allow a player to update their bet", async () => {
     // place bet
     const prediction = { bear: {} };
@@ -208,6 +270,26 @@ describe("Place Bet", () => {
     }
   });
 
+  it("should not allow bets without stake", async () => {
+    try {
+      await placeBet(
+        program,
+        gamePDA,
+        roundPDA,
+        roundVaultPDA,
+        tokenAddress,
+        player,
+        playerTokenAccount,
+        { bull: {} },
+        0
+      );
+      expect.fail("Bet without stake should be rejected.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("InvalidBetAmount");
+    }
+  });
+
   it("should not allow bets on a closed round", async () => {
     // warp by 5 slots -> increase timestamp by 2 seconds
     await warpToSlot(provider, 2);
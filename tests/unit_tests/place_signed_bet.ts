@@ -0,0 +1,204 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+import { approve, getAccount } from "@solana/spl-token";
+
+import { INTERVAL, FEE } from "../config";
+import {
+  airdrop,
+  getOracle,
+  getToken,
+  getTokenAccount,
+  initializeBetNonce,
+  initializeGame,
+  initializeProtocol,
+  initializeRound,
+  placeBetSigned,
+  startRound,
+} from "../helpers";
+
+describe("Place Signed Bet", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let relayer: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let playerTokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let roundPDA: PublicKey;
+  let roundVaultPDA: PublicKey;
+  let betNoncePDA: PublicKey;
+  let priceFeedAddr: PublicKey;
+  let expiry: number;
+  const amount = 100 * 10 ** 9;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+    relayer = anchor.web3.Keypair.generate();
+
+    // Fund accounts (the player needs SOL only to set up the delegation)
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+    await airdrop(provider.connection, relayer.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    playerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player
+    );
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      INTERVAL,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    // relayer creates the nonce account, player delegates to it
+    betNoncePDA = await initializeBetNonce(program, relayer, player.publicKey);
+    await approve(
+      provider.connection,
+      player,
+      playerTokenAccount.address,
+      betNoncePDA,
+      player,
+      10 * amount
+    );
+
+    expiry = Math.floor(Date.now() / 1000) + 600;
+  });
+
+  it("should place a bet signed by the player", async () => {
+    const betPDA = await placeBetSigned(
+      program,
+      relayer,
+      player,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      { bull: {} },
+      amount,
+      0,
+      expiry
+    );
+
+    const bet = await program.account.bet.fetch(betPDA);
+    expect(bet.player.toString()).to.equal(player.publicKey.toString());
+    expect(bet.amount.toNumber()).to.equal(amount);
+
+    // stake is pulled from the player
+    const vaultBalance = (await getAccount(provider.connection, roundVaultPDA))
+      .amount;
+    expect(Number(vaultBalance)).to.equal(amount);
+
+    // nonce is used up
+    const betNonce = await program.account.betNonce.fetch(betNoncePDA);
+    expect(betNonce.nonce.toNumber()).to.equal(1);
+  });
+
+  it("should not accept an intent signed by someone else", async () => {
+    try {
+      await placeBetSigned(
+        program,
+        relayer,
+        player,
+        gamePDA,
+        roundPDA,
+        roundVaultPDA,
+        tokenAddress,
+        { bull: {} },
+        amount,
+        0,
+        expiry,
+        relayer
+      );
+      expect.fail("Bet should not be placed.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("InvalidSignature");
+    }
+  });
+
+  it("should not accept a stale nonce", async () => {
+    try {
+      await placeBetSigned(
+        program,
+        relayer,
+        player,
+        gamePDA,
+        roundPDA,
+        roundVaultPDA,
+        tokenAddress,
+        { bull: {} },
+        amount,
+        5,
+        expiry
+      );
+      expect.fail("Bet should not be placed.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("InvalidNonce");
+    }
+  });
+
+  it("should not accept an expired intent", async () => {
+    try {
+      await placeBetSigned(
+        program,
+        relayer,
+        player,
+        gamePDA,
+        roundPDA,
+        roundVaultPDA,
+        tokenAddress,
+        { bull: {} },
+        amount,
+        0,
+        1
+      );
+      expect.fail("Bet should not be placed.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("IntentExpired");
+    }
+  });
+});
@@ -0,0 +1,262 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+
+import { INTERVAL, SLOT_OFFSET, FEE } from "../config";
+import { pullOracleClient } from "../mock_oracle";
+import {
+  airdrop,
+  cancelBet,
+  claimPrize,
+  closeBetting,
+  endRound,
+  getOracle,
+  getToken,
+  getTokenAccount,
+  initializeGame,
+  initializePlayerStats,
+  initializeProtocol,
+  initializeRound,
+  placeBet,
+  recordLostBet,
+  setOraclePrice,
+  startRound,
+  warpToSlot,
+} from "../helpers";
+
+describe("Player Stats", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let player2: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let playerTokenAccount: splToken.Account;
+  let player2TokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let roundPDA: PublicKey;
+  let roundVaultPDA: PublicKey;
+  let winnerBetPDA: PublicKey;
+  let loserBetPDA: PublicKey;
+  let winnerStatsPDA: PublicKey;
+  let loserStatsPDA: PublicKey;
+  let priceFeedAddr: PublicKey;
+  let pullOracle: pullOracleClient;
+  const amount = 100 * 10 ** 9;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+    player2 = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+    await airdrop(provider.connection, player2.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    playerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player
+    );
+
+    player2TokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player2
+    );
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+    pullOracle = oracle.pullOracle;
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 60);
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      INTERVAL,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    winnerStatsPDA = await initializePlayerStats(
+      program,
+      player,
+      player.publicKey,
+      gamePDA
+    );
+    loserStatsPDA = await initializePlayerStats(
+      program,
+      player2,
+      player2.publicKey,
+      gamePDA
+    );
+
+    [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    winnerBetPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      { bear: {} },
+      amount
+    );
+    loserBetPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player2,
+      player2TokenAccount,
+      { bull: {} },
+      amount
+    );
+
+    // bear wins
+    await warpToSlot(provider, SLOT_OFFSET);
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 30);
+    await endRound(
+      program,
+      game_authority,
+      gamePDA,
+      roundPDA,
+      gameVaultPDA,
+      roundVaultPDA,
+      tokenAddress,
+      priceFeedAddr
+    );
+  });
+
+  it("should record wagers and wins", async () => {
+    await claimPrize(
+      program,
+      player,
+      gamePDA,
+      roundPDA,
+      winnerBetPDA,
+      tokenAddress,
+      roundVaultPDA,
+      playerTokenAccount
+    );
+
+    const stats = await program.account.playerStats.fetch(winnerStatsPDA);
+    expect(stats.totalWagered.toNumber()).to.equal(amount);
+    expect(stats.totalWon.toNumber()).to.equal(2 * amount);
+    expect(stats.wins).to.equal(1);
+    expect(stats.currentStreak).to.equal(1);
+    expect(stats.bestStreak).to.equal(1);
+    expect(stats.lastRound).to.equal(0);
+  });
+
+  it("should let anyone record a loss once", async () => {
+    await recordLostBet(program, game_authority, gamePDA, roundPDA, loserBetPDA);
+
+    const stats = await program.account.playerStats.fetch(loserStatsPDA);
+    expect(stats.losses).to.equal(1);
+    expect(stats.currentStreak).to.equal(0);
+    expect(await provider.connection.getAccountInfo(loserBetPDA)).to.be.null;
+  });
+
+  it("should not record a loss for a winning bet", async () => {
+    try {
+      await recordLostBet(
+        program,
+        game_authority,
+        gamePDA,
+        roundPDA,
+        winnerBetPDA
+      );
+      expect.fail("Loss should not be recorded.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("BetNotLost");
+    }
+  });
+
+  it("should settle the pending bet before the next wager", async () => {
+    const [nextRoundPDA, nextRoundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(
+      program,
+      game_authority,
+      gamePDA,
+      nextRoundPDA,
+      priceFeedAddr
+    );
+
+    // loss of the first round is recorded without record_bet_loss
+    const betPDA = await placeBet(
+      program,
+      gamePDA,
+      nextRoundPDA,
+      nextRoundVaultPDA,
+      tokenAddress,
+      player2,
+      player2TokenAccount,
+      { bull: {} },
+      amount
+    );
+    let stats = await program.account.playerStats.fetch(loserStatsPDA);
+    expect(stats.losses).to.equal(1);
+    expect(stats.totalWagered.toNumber()).to.equal(2 * amount);
+    expect(stats.lastBet.toString()).to.equal(betPDA.toString());
+    expect(stats.lastRound).to.equal(1);
+
+    // cancelled wagers are removed
+    await cancelBet(
+      program,
+      player2,
+      gamePDA,
+      nextRoundPDA,
+      betPDA,
+      tokenAddress,
+      nextRoundVaultPDA,
+      player2TokenAccount
+    );
+    stats = await program.account.playerStats.fetch(loserStatsPDA);
+    expect(stats.totalWagered.toNumber()).to.equal(amount);
+    expect(stats.lastBet.toString()).to.equal(PublicKey.default.toString());
+    expect(stats.lastRound).to.equal(0);
+  });
+});
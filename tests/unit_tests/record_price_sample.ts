@@ -0,0 +1,135 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+
+import { INTERVAL, FEE } from "../config";
+import {
+  airdrop,
+  getOracle,
+  getRoundPDA,
+  getToken,
+  initializeGame,
+  initializeProtocol,
+  initializeRound,
+  recordPriceSample,
+  setOraclePrice,
+  startRound,
+  updateInterval,
+  updateTwapWindow,
+} from "../helpers";
+import { pullOracleClient } from "../mock_oracle";
+
+describe("Record Price Sample", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let roundPDA: PublicKey;
+  let roundVaultPDA: PublicKey;
+  let priceFeedAddr: PublicKey;
+  let pullOracle: pullOracleClient;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+
+    // create token
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+    pullOracle = oracle.pullOracle;
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      INTERVAL,
+      tokenAddress,
+      priceFeedAddr
+    );
+  });
+
+  it("should not allow a twap window longer than half the interval", async () => {
+    try {
+      const pda = await getRoundPDA(program, gamePDA);
+      await updateTwapWindow(program, game_authority, gamePDA, pda, 1);
+      expect.fail("TWAP window should not exceed half the interval.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("InvalidTwapWindow");
+    }
+  });
+
+  it("should not record samples if twap is disabled", async () => {
+    [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    try {
+      await recordPriceSample(program, gamePDA, roundPDA, priceFeedAddr);
+      expect.fail("Samples should not be recorded without twap.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("TwapNotEnabled");
+    }
+  });
+
+  it("should record start samples during the start window", async () => {
+    // enable twap
+    await updateInterval(program, game_authority, gamePDA, 600);
+    const pda = await getRoundPDA(program, gamePDA);
+    await updateTwapWindow(program, game_authority, gamePDA, pda, 60);
+
+    [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    // record a new price
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 110);
+    await recordPriceSample(program, gamePDA, roundPDA, priceFeedAddr);
+
+    // opening price and new price are recorded
+    const round = await program.account.round.fetch(roundPDA);
+    expect(round.startSamples.len).to.equal(2);
+    expect(round.endSamples.len).to.equal(0);
+
+    // same price cannot be recorded twice
+    try {
+      await recordPriceSample(program, gamePDA, roundPDA, priceFeedAddr);
+      expect.fail("Sample should not be recorded twice.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("PriceSampleAlreadyRecorded");
+    }
+  });
+});
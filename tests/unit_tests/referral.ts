@@ -0,0 +1,332 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SendTransactionError } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { assert, expect } from "chai";
+import * as splToken from "@solana/spl-token";
+import { getAccount } from "@solana/spl-token";
+
+import { INTERVAL, SLOT_OFFSET, FEE } from "../config";
+import { pullOracleClient } from "../mock_oracle";
+import {
+  airdrop,
+  bindReferrer,
+  claimPrize,
+  claimReferralRewards,
+  closeBetting,
+  endRound,
+  getOracle,
+  getRoundPDA,
+  getToken,
+  getTokenAccount,
+  initializeGame,
+  initializeProtocol,
+  initializeReferralRewards,
+  initializeRound,
+  placeBet,
+  setOraclePrice,
+  startRound,
+  updateReferralShare,
+  updateSettlementFee,
+  warpToSlot,
+} from "../helpers";
+
+describe("Referral", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let player2: Keypair;
+  let referrer: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let playerTokenAccount: splToken.Account;
+  let player2TokenAccount: splToken.Account;
+  let referrerTokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let referralRewardsPDA: PublicKey;
+  let priceFeedAddr: PublicKey;
+  let pullOracle: pullOracleClient;
+  const amount = 100 * 10 ** 9;
+  const settlementFeeBps = 1000;
+  const referralShareBps = 2000;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+    player2 = anchor.web3.Keypair.generate();
+    referrer = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+    await airdrop(provider.connection, player2.publicKey);
+    await airdrop(provider.connection, referrer.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    playerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player
+    );
+
+    player2TokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player2
+    );
+
+    referrerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      referrer
+    );
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+    pullOracle = oracle.pullOracle;
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 60);
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      INTERVAL,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    const pda = await getRoundPDA(program, gamePDA);
+    await updateSettlementFee(
+      program,
+      game_authority,
+      gamePDA,
+      pda,
+      settlementFeeBps
+    );
+    await updateReferralShare(
+      program,
+      game_authority,
+      gamePDA,
+      pda,
+      referralShareBps
+    );
+
+    await bindReferrer(program, player, referrer.publicKey);
+    referralRewardsPDA = await initializeReferralRewards(
+      program,
+      referrer,
+      referrer.publicKey,
+      gamePDA
+    );
+  });
+
+  it("should accrue a share of the settlement fee to the referrer", async () => {
+    const [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    const betPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      { bear: {} },
+      amount
+    );
+    await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player2,
+      player2TokenAccount,
+      { bull: {} },
+      amount
+    );
+    const bet = await program.account.bet.fetch(betPDA);
+    expect(bet.referrer.toString()).to.equal(referrer.publicKey.toString());
+
+    // bear wins
+    await warpToSlot(provider, SLOT_OFFSET);
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 30);
+    await endRound(
+      program,
+      game_authority,
+      gamePDA,
+      roundPDA,
+      gameVaultPDA,
+      roundVaultPDA,
+      tokenAddress,
+      priceFeedAddr
+    );
+    await claimPrize(
+      program,
+      player,
+      gamePDA,
+      roundPDA,
+      betPDA,
+      tokenAddress,
+      roundVaultPDA,
+      playerTokenAccount
+    );
+
+    // the winner bore the whole fee on the losing stake
+    const reward = (amount * settlementFeeBps * referralShareBps) / 10 ** 8;
+    let rewards = await program.account.referralRewards.fetch(
+      referralRewardsPDA
+    );
+    expect(rewards.accrued.toNumber()).to.equal(reward);
+    // accrued rewards are no longer house funds
+    let game = await program.account.game.fetch(gamePDA);
+    expect(game.referralLiability.toNumber()).to.equal(reward);
+
+    const initialBalance = (
+      await getAccount(provider.connection, referrerTokenAccount.address)
+    ).amount;
+    await claimReferralRewards(
+      program,
+      referrer,
+      gamePDA,
+      tokenAddress,
+      referrerTokenAccount
+    );
+    const finalBalance = (
+      await getAccount(provider.connection, referrerTokenAccount.address)
+    ).amount;
+    expect(finalBalance - initialBalance).to.equal(BigInt(reward));
+
+    rewards = await program.account.referralRewards.fetch(referralRewardsPDA);
+    expect(rewards.accrued.toNumber()).to.equal(0);
+    expect(rewards.claimed.toNumber()).to.equal(reward);
+    game = await program.account.game.fetch(gamePDA);
+    expect(game.referralLiability.toNumber()).to.equal(0);
+  });
+
+  it("should pay prizes of referrers without rewards account", async () => {
+    // referrer of player2 never opened rewards for the game
+    await bindReferrer(
+      program,
+      player2,
+      anchor.web3.Keypair.generate().publicKey
+    );
+
+    const [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      { bear: {} },
+      amount
+    );
+    const betPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player2,
+      player2TokenAccount,
+      { bull: {} },
+      amount
+    );
+
+    // bull wins
+    await warpToSlot(provider, SLOT_OFFSET);
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 90);
+    await endRound(
+      program,
+      game_authority,
+      gamePDA,
+      roundPDA,
+      gameVaultPDA,
+      roundVaultPDA,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    const initialBalance = (
+      await getAccount(provider.connection, player2TokenAccount.address)
+    ).amount;
+    await claimPrize(
+      program,
+      player2,
+      gamePDA,
+      roundPDA,
+      betPDA,
+      tokenAddress,
+      roundVaultPDA,
+      player2TokenAccount
+    );
+    const finalBalance = (
+      await getAccount(provider.connection, player2TokenAccount.address)
+    ).amount;
+    const fee = (amount * settlementFeeBps) / 10 ** 4;
+    expect(finalBalance - initialBalance).to.equal(BigInt(2 * amount - fee));
+  });
+
+  it("should not allow rebinding the referrer", async () => {
+    try {
+      await bindReferrer(program, player, player2.publicKey);
+      expect.fail("Referrer should only be bound once.");
+    } catch (err) {
+      if (err instanceof SendTransactionError) {
+        assert.include(err.message, "already in use");
+      } else {
+        console.error("Unexpected Error:", err);
+        assert.fail("Unexpected error during transaction.");
+      }
+    }
+  });
+
+  it("should not allow players to refer themselves", async () => {
+    try {
+      await bindReferrer(program, player2, player2.publicKey);
+      expect.fail("Player should not refer themselves.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("InvalidReferrer");
+    }
+  });
+});
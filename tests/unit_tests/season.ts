@@ -0,0 +1,350 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+import { getAccount } from "@solana/spl-token";
+
+import { INTERVAL, SLOT_OFFSET, FEE } from "../config";
+import { pullOracleClient } from "../mock_oracle";
+import {
+  airdrop,
+  challengeRanking,
+  claimPrize,
+  claimSeasonPrize,
+  closeBetting,
+  commitRanking,
+  createSeason,
+  endRound,
+  getOracle,
+  getRoundPDA,
+  getSeasonEntryPDA,
+  getToken,
+  getTokenAccount,
+  initializeGame,
+  initializeProtocol,
+  initializeRound,
+  joinSeason,
+  placeBet,
+  setOraclePrice,
+  startRound,
+  updateSettlementFee,
+  warpToSlot,
+} from "../helpers";
+
+describe("Season", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let player2: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let playerTokenAccount: splToken.Account;
+  let player2TokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let seasonPDA: PublicKey;
+  let seasonVault: PublicKey;
+  let seasonEnd: number;
+  let priceFeedAddr: PublicKey;
+  let pullOracle: pullOracleClient;
+  const amount = 100 * 10 ** 9;
+  const settlementFeeBps = 1000;
+  const feeShareBps = 5000;
+  // fee on the losing stakes, half of it goes to the season
+  const seasonShare = (amount * settlementFeeBps * feeShareBps) / 10 ** 8;
+
+  // plays a round where player wins against player2
+  async function playRound(afterBets = async () => {}) {
+    const [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    const betPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      { bear: {} },
+      amount
+    );
+    await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player2,
+      player2TokenAccount,
+      { bull: {} },
+      amount
+    );
+    await afterBets();
+
+    // bear wins
+    await warpToSlot(provider, SLOT_OFFSET);
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 30);
+    await endRound(
+      program,
+      game_authority,
+      gamePDA,
+      roundPDA,
+      gameVaultPDA,
+      roundVaultPDA,
+      tokenAddress,
+      priceFeedAddr
+    );
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 60);
+
+    await claimPrize(
+      program,
+      player,
+      gamePDA,
+      roundPDA,
+      betPDA,
+      tokenAddress,
+      roundVaultPDA,
+      playerTokenAccount
+    );
+  }
+
+  async function createTestSeason(
+    payoutBps: number[],
+    challengePeriod = 0,
+    join = true
+  ) {
+    const now = Math.floor(Date.now() / 1000);
+    seasonEnd = now + 10;
+    [seasonPDA, seasonVault] = await createSeason(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress,
+      0,
+      now - 60,
+      seasonEnd,
+      feeShareBps,
+      payoutBps,
+      challengePeriod
+    );
+    if (join) {
+      await joinSeason(program, player, player.publicKey, gamePDA, seasonPDA);
+      await joinSeason(program, player2, player2.publicKey, gamePDA, seasonPDA);
+    }
+  }
+
+  async function waitForSeasonEnd() {
+    const remaining = seasonEnd - Math.floor(Date.now() / 1000) + 2;
+    await new Promise((resolve) => setTimeout(resolve, remaining * 1000));
+  }
+
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+    player2 = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+    await airdrop(provider.connection, player2.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    playerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player
+    );
+
+    player2TokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player2
+    );
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+    pullOracle = oracle.pullOracle;
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 60);
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      INTERVAL,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    await updateSettlementFee(
+      program,
+      game_authority,
+      gamePDA,
+      await getRoundPDA(program, gamePDA),
+      settlementFeeBps
+    );
+  });
+
+  it("should take a cut of the settlement fee and track net winnings", async () => {
+    await createTestSeason([7000, 3000]);
+    await playRound();
+
+    const seasonBalance = (await getAccount(provider.connection, seasonVault))
+      .amount;
+    expect(seasonBalance).to.equal(BigInt(seasonShare));
+
+    // winner gets both stakes less the fee
+    const fee = (amount * settlementFeeBps) / 10 ** 4;
+    const entry = await program.account.seasonEntry.fetch(
+      await getSeasonEntryPDA(program, seasonPDA, player.publicKey)
+    );
+    expect(entry.netWinnings.toNumber()).to.equal(amount - fee);
+    const entry2 = await program.account.seasonEntry.fetch(
+      await getSeasonEntryPDA(program, seasonPDA, player2.publicKey)
+    );
+    expect(entry2.netWinnings.toNumber()).to.equal(-amount);
+  });
+
+  it("should only count prizes of bets staked during the season", async () => {
+    await createTestSeason([7000, 3000], 0, false);
+
+    // both players join after their stakes were placed
+    await playRound(async () => {
+      await joinSeason(program, player, player.publicKey, gamePDA, seasonPDA);
+      await joinSeason(program, player2, player2.publicKey, gamePDA, seasonPDA);
+    });
+
+    const entry = await program.account.seasonEntry.fetch(
+      await getSeasonEntryPDA(program, seasonPDA, player.publicKey)
+    );
+    expect(entry.netWinnings.toNumber()).to.equal(0);
+    const entry2 = await program.account.seasonEntry.fetch(
+      await getSeasonEntryPDA(program, seasonPDA, player2.publicKey)
+    );
+    expect(entry2.netWinnings.toNumber()).to.equal(0);
+  });
+
+  it("should count prizes claimed after the season until it is ranked", async () => {
+    await createTestSeason([7000, 3000]);
+    // the stakes are placed during the season, the prize is claimed after it
+    await playRound(waitForSeasonEnd);
+
+    const fee = (amount * settlementFeeBps) / 10 ** 4;
+    const entry = await program.account.seasonEntry.fetch(
+      await getSeasonEntryPDA(program, seasonPDA, player.publicKey)
+    );
+    expect(entry.netWinnings.toNumber()).to.equal(amount - fee);
+  });
+
+  it("should pay the top places of a verified ranking", async () => {
+    await createTestSeason([7000, 3000]);
+    await playRound();
+    await waitForSeasonEnd();
+
+    // ranking needs to be ordered by net winnings
+    try {
+      await commitRanking(
+        program,
+        game_authority,
+        gamePDA,
+        seasonPDA,
+        tokenAddress,
+        [player2.publicKey, player.publicKey]
+      );
+      expect.fail("Ranking should be rejected.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("InvalidRanking");
+    }
+
+    await commitRanking(
+      program,
+      game_authority,
+      gamePDA,
+      seasonPDA,
+      tokenAddress,
+      [player.publicKey, player2.publicKey]
+    );
+
+    const initialBalance = (
+      await getAccount(provider.connection, player2TokenAccount.address)
+    ).amount;
+    await claimSeasonPrize(
+      program,
+      player2,
+      gamePDA,
+      seasonPDA,
+      tokenAddress,
+      player2TokenAccount
+    );
+    const finalBalance = (
+      await getAccount(provider.connection, player2TokenAccount.address)
+    ).amount;
+    expect(finalBalance - initialBalance).to.equal(
+      BigInt((seasonShare * 3000) / 10 ** 4)
+    );
+
+    try {
+      await claimSeasonPrize(
+        program,
+        player2,
+        gamePDA,
+        seasonPDA,
+        tokenAddress,
+        player2TokenAccount
+      );
+      expect.fail("Prize should only be claimed once.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("SeasonPrizeClaimed");
+    }
+  });
+
+  it("should reject a ranking outranked by an unranked entry", async () => {
+    await createTestSeason([10000], 60);
+    await playRound();
+    await waitForSeasonEnd();
+
+    await commitRanking(
+      program,
+      game_authority,
+      gamePDA,
+      seasonPDA,
+      tokenAddress,
+      [player2.publicKey]
+    );
+    await challengeRanking(program, player, seasonPDA, player.publicKey);
+
+    const season = await program.account.season.fetch(seasonPDA);
+    expect(season.numRanked).to.equal(0);
+    expect(season.rankedAt.toNumber()).to.equal(0);
+  });
+});
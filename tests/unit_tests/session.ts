@@ -0,0 +1,258 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+import { getAccount } from "@solana/spl-token";
+
+import { INTERVAL, SLOT_OFFSET, FEE } from "../config";
+import { pullOracleClient } from "../mock_oracle";
+import {
+  airdrop,
+  claimPrizeWithSession,
+  closeBetting,
+  createAutoBet,
+  createSession,
+  endRound,
+  getOracle,
+  getToken,
+  getTokenAccount,
+  initializeGame,
+  initializeProtocol,
+  initializeRound,
+  placeBetWithSession,
+  revokeSession,
+  setOraclePrice,
+  startRound,
+  warpToSlot,
+} from "../helpers";
+
+describe("Session", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let sessionKey: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let playerTokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let roundPDA: PublicKey;
+  let roundVaultPDA: PublicKey;
+  let sessionPDA: PublicKey;
+  let priceFeedAddr: PublicKey;
+  let pullOracle: pullOracleClient;
+  const amount = 100 * 10 ** 9;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+    sessionKey = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+    await airdrop(provider.connection, sessionKey.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    playerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player
+    );
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+    pullOracle = oracle.pullOracle;
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 60);
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      INTERVAL,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    // session valid for one hour with a limit of two bets
+    sessionPDA = await createSession(
+      program,
+      player,
+      sessionKey.publicKey,
+      tokenAddress,
+      playerTokenAccount,
+      Math.floor(Date.now() / 1000) + 3600,
+      2 * amount
+    );
+  });
+
+  it("should let the session key place bets for the player", async () => {
+    const betPDA = await placeBetWithSession(
+      program,
+      sessionKey,
+      player.publicKey,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      { bull: {} },
+      amount
+    );
+
+    const bet = await program.account.bet.fetch(betPDA);
+    expect(bet.player.toString()).to.equal(player.publicKey.toString());
+
+    const session = await program.account.session.fetch(sessionPDA);
+    expect(session.spent.toNumber()).to.equal(amount);
+  });
+
+  it("should not exceed the spend limit", async () => {
+    try {
+      await placeBetWithSession(
+        program,
+        sessionKey,
+        player.publicKey,
+        gamePDA,
+        roundPDA,
+        roundVaultPDA,
+        tokenAddress,
+        { bull: {} },
+        3 * amount
+      );
+      expect.fail("Bet should not be placed.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("SessionLimitExceeded");
+    }
+  });
+
+  it("should pay session claims to the main wallet", async () => {
+    const betPDA = await placeBetWithSession(
+      program,
+      sessionKey,
+      player.publicKey,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      { bear: {} },
+      amount
+    );
+
+    await warpToSlot(provider, SLOT_OFFSET);
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 30);
+    await endRound(
+      program,
+      game_authority,
+      gamePDA,
+      roundPDA,
+      gameVaultPDA,
+      roundVaultPDA,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    const initialBalance = (
+      await getAccount(provider.connection, playerTokenAccount.address)
+    ).amount;
+    await claimPrizeWithSession(
+      program,
+      sessionKey,
+      player.publicKey,
+      gamePDA,
+      roundPDA,
+      betPDA,
+      tokenAddress,
+      roundVaultPDA
+    );
+    const finalBalance = (
+      await getAccount(provider.connection, playerTokenAccount.address)
+    ).amount;
+    expect(Number(finalBalance - initialBalance)).to.equal(amount);
+  });
+
+  it("should not allow bets after the session is revoked", async () => {
+    await revokeSession(
+      program,
+      player,
+      sessionKey.publicKey,
+      tokenAddress,
+      playerTokenAccount
+    );
+    expect(await provider.connection.getAccountInfo(sessionPDA)).to.be.null;
+
+    try {
+      await placeBetWithSession(
+        program,
+        sessionKey,
+        player.publicKey,
+        gamePDA,
+        roundPDA,
+        roundVaultPDA,
+        tokenAddress,
+        { bull: {} },
+        amount
+      );
+      expect.fail("Bet should not be placed.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("AccountNotInitialized");
+    }
+  });
+
+  it("should not let an auto-bet take over the session allowance", async () => {
+    try {
+      await createAutoBet(
+        program,
+        player,
+        gamePDA,
+        tokenAddress,
+        playerTokenAccount,
+        { bull: {} },
+        amount,
+        2
+      );
+      expect.fail("Auto-bet should be rejected.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("DelegateInUse");
+    }
+
+    // session keeps its allowance
+    const tokenAccount = await getAccount(
+      provider.connection,
+      playerTokenAccount.address
+    );
+    expect(tokenAccount.delegate.toString()).to.equal(sessionPDA.toString());
+    expect(Number(tokenAccount.delegatedAmount)).to.equal(2 * amount);
+  });
+});
@@ -0,0 +1,159 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+import { getAccount } from "@solana/spl-token";
+
+import { INTERVAL, SLOT_OFFSET, FEE } from "../config";
+import {
+  airdrop,
+  closeBetting,
+  getOracle,
+  getRoundPDA,
+  getToken,
+  getTokenAccount,
+  initializeGame,
+  initializeProtocol,
+  initializeRound,
+  placeBet,
+  startRound,
+  switchBet,
+  updateSwitchFee,
+  warpToSlot,
+} from "../helpers";
+
+describe("Switch Bet", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let playerTokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let roundPDA: PublicKey;
+  let roundVaultPDA: PublicKey;
+  let betPDA: PublicKey;
+  let priceFeedAddr: PublicKey;
+  const amount = 100 * 10 ** 9;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    playerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player
+    );
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      INTERVAL,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    // 1% fee
+    const pda = await getRoundPDA(program, gamePDA);
+    await updateSwitchFee(program, game_authority, gamePDA, pda, 100);
+
+    [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    betPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      { bull: {} },
+      amount
+    );
+  });
+
+  it("should move the stake to the other side minus the fee", async () => {
+    await switchBet(
+      program,
+      player,
+      gamePDA,
+      roundPDA,
+      betPDA,
+      tokenAddress,
+      roundVaultPDA,
+      { bear: {} }
+    );
+
+    const bet = await program.account.bet.fetch(betPDA);
+    expect(bet.outcome).to.equal(1);
+    expect(bet.amount.toNumber()).to.equal(99 * 10 ** 9);
+
+    const round = await program.account.round.fetch(roundPDA);
+    expect(round.totals[0].toNumber()).to.equal(0);
+    expect(round.totals[1].toNumber()).to.equal(99 * 10 ** 9);
+
+    // fee goes to the game vault
+    const houseBalance = (await getAccount(provider.connection, gameVaultPDA))
+      .amount;
+    expect(Number(houseBalance)).to.equal(1 * 10 ** 9);
+  });
+
+  it("should not allow switching after betting closed", async () => {
+    await warpToSlot(provider, SLOT_OFFSET);
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+
+    try {
+      await switchBet(
+        program,
+        player,
+        gamePDA,
+        roundPDA,
+        betPDA,
+        tokenAddress,
+        roundVaultPDA,
+        { bear: {} }
+      );
+      expect.fail("Bet should not be switched.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("BettingIsClosed");
+    }
+  });
+});
@@ -0,0 +1,166 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+
+import { INTERVAL, FEE } from "../config";
+import {
+  airdrop,
+  getAllowlistTree,
+  getOracle,
+  getRoundPDA,
+  getToken,
+  getTokenAccount,
+  initializeGame,
+  initializeProtocol,
+  initializeRound,
+  placeBet,
+  startRound,
+  updateAllowlist,
+} from "../helpers";
+
+describe("Update Allowlist", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let outsider: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let playerTokenAccount: splToken.Account;
+  let outsiderTokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let roundPDA: PublicKey;
+  let roundVaultPDA: PublicKey;
+  let priceFeedAddr: PublicKey;
+  let proofs: number[][][];
+  const amount = 100 * 10 ** 9;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+    outsider = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+    await airdrop(provider.connection, outsider.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    playerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player
+    );
+    outsiderTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      outsider
+    );
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      INTERVAL,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    // invite the player and two other wallets
+    const tree = getAllowlistTree([
+      anchor.web3.Keypair.generate().publicKey,
+      player.publicKey,
+      anchor.web3.Keypair.generate().publicKey,
+    ]);
+    proofs = tree.proofs;
+    const pda = await getRoundPDA(program, gamePDA);
+    await updateAllowlist(program, game_authority, gamePDA, pda, tree.root);
+
+    [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+  });
+
+  it("should allow invited players to bet with a proof", async () => {
+    const betPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      { bull: {} },
+      amount,
+      proofs[1]
+    );
+
+    const bet = await program.account.bet.fetch(betPDA);
+    expect(bet.amount.toNumber()).to.equal(amount);
+  });
+
+  it("should not allow players outside the allowlist", async () => {
+    try {
+      await placeBet(
+        program,
+        gamePDA,
+        roundPDA,
+        roundVaultPDA,
+        tokenAddress,
+        outsider,
+        outsiderTokenAccount,
+        { bull: {} },
+        amount,
+        proofs[1]
+      );
+      expect.fail("Bet should not be placed.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("NotAllowlisted");
+    }
+  });
+
+  it("should not allow updating the root during a round", async () => {
+    try {
+      await updateAllowlist(
+        program,
+        game_authority,
+        gamePDA,
+        roundPDA,
+        new Array(32).fill(0)
+      );
+      expect.fail("Allowlist should not be updated.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("RoundInProgress");
+    }
+  });
+});
@@ -0,0 +1,225 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+import { getAccount } from "@solana/spl-token";
+
+import { INTERVAL, SLOT_OFFSET, FEE } from "../config";
+import {
+  airdrop,
+  claimPrize,
+  closeBetting,
+  endRound,
+  getOracle,
+  getRoundPDA,
+  getToken,
+  getTokenAccount,
+  initializeGame,
+  initializeProtocol,
+  initializeRound,
+  placeBet,
+  startRound,
+  setOraclePrice,
+  updateBuckets,
+  warpToSlot,
+} from "../helpers";
+import { pullOracleClient } from "../mock_oracle";
+
+describe("Update Buckets", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let player2: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let playerTokenAccount: splToken.Account;
+  let player2TokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let priceFeedAddr: PublicKey;
+  let pullOracle: pullOracleClient;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+    player2 = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+    await airdrop(provider.connection, player2.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    playerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player
+    );
+
+    player2TokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player2
+    );
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+    pullOracle = oracle.pullOracle;
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      INTERVAL,
+      tokenAddress,
+      priceFeedAddr
+    );
+  });
+
+  const bounds = [-200, -50, 50, 200];
+
+  it("should pay the bucket of the price change", async () => {
+    const pda = await getRoundPDA(program, gamePDA);
+    await updateBuckets(program, game_authority, gamePDA, pda, bounds);
+
+    // check market
+    const game = await program.account.game.fetch(gamePDA);
+    expect(Object.keys(game.market)[0].toString()).to.equal("buckets");
+    expect(game.numBuckets).to.equal(bounds.length + 1);
+
+    const [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    // place bets on +0.5..2% and ±0.5%
+    const amount = 100 * 10 ** 9;
+    const betPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      3,
+      amount
+    );
+    await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player2,
+      player2TokenAccount,
+      2,
+      amount
+    );
+
+    // price moves by +1%
+    await warpToSlot(provider, SLOT_OFFSET);
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 101);
+    await endRound(
+      program,
+      game_authority,
+      gamePDA,
+      roundPDA,
+      gameVaultPDA,
+      roundVaultPDA,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    // check winning bucket
+    const winningOutcome = (await program.account.round.fetch(roundPDA))
+      .winningOutcome;
+    expect(winningOutcome).to.equal(3);
+
+    // claim prize
+    const initialBalance = (
+      await getAccount(provider.connection, playerTokenAccount.address)
+    ).amount;
+    await claimPrize(
+      program,
+      player,
+      gamePDA,
+      roundPDA,
+      betPDA,
+      tokenAddress,
+      roundVaultPDA,
+      playerTokenAccount
+    );
+    const finalBalance = (
+      await getAccount(provider.connection, playerTokenAccount.address)
+    ).amount;
+    expect(Number(finalBalance - initialBalance)).to.equal(2 * amount);
+  });
+
+  it("should not allow bets on undefined buckets", async () => {
+    const pda = await getRoundPDA(program, gamePDA);
+    await updateBuckets(program, game_authority, gamePDA, pda, bounds);
+
+    const [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    try {
+      await placeBet(
+        program,
+        gamePDA,
+        roundPDA,
+        roundVaultPDA,
+        tokenAddress,
+        player,
+        playerTokenAccount,
+        bounds.length + 1,
+        100 * 10 ** 9
+      );
+      expect.fail("Bets on undefined buckets should be rejected.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("InvalidPrediction");
+    }
+  });
+
+  it("should not allow bounds that are not increasing", async () => {
+    try {
+      const pda = await getRoundPDA(program, gamePDA);
+      await updateBuckets(program, game_authority, gamePDA, pda, [50, -50]);
+      expect.fail("Bounds should be increasing.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("InvalidBuckets");
+    }
+  });
+});
@@ -0,0 +1,180 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+import { confirmTransaction } from "@solana-developers/helpers";
+
+import { INTERVAL, SLOT_OFFSET, FEE, SOL_feedId, WIF_feedId } from "../config";
+import {
+  airdrop,
+  getOracle,
+  getRoundPDA,
+  getToken,
+  getTokenAccount,
+  initializeGame,
+  initializeProtocol,
+  initializeRound,
+  startRound,
+  updateFeedAccount,
+} from "../helpers";
+import { pullOracleClient } from "../mock_oracle";
+
+describe("Update Feed Account", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let roundInterval: number;
+  let slot_offset: number;
+  let protocolPDA: PublicKey;
+  let game_fee: number;
+  let tokenAddress: PublicKey;
+  let gameAuthorityTokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let priceFeedAddr: PublicKey;
+  let pullOracle: pullOracleClient;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    gameAuthorityTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      game_authority
+    );
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+    pullOracle = oracle.pullOracle;
+
+    // initialize parameters
+    game_fee = FEE;
+    roundInterval = INTERVAL;
+    slot_offset = SLOT_OFFSET;
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, game_fee);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      roundInterval,
+      tokenAddress,
+      priceFeedAddr
+    );
+  });
+
+  it("should be able to move the game to a new feed account", async () => {
+    // create a second price account for the same feed
+    const [txId, newFeedAddr] = await pullOracle.createOracle(
+      SOL_feedId,
+      100,
+      -9
+    );
+    await confirmTransaction(provider.connection, txId);
+
+    const roundPDA = await getRoundPDA(program, gamePDA);
+    await updateFeedAccount(
+      program,
+      game_authority,
+      gamePDA,
+      roundPDA,
+      newFeedAddr
+    );
+
+    // check feed account
+    const feedAccount = (await program.account.game.fetch(gamePDA))
+      .feedAccount;
+    expect(feedAccount.toString()).to.equal(newFeedAddr.toString());
+  });
+
+  it("should reject a feed account with a different feed id", async () => {
+    const [txId, wifFeedAddr] = await pullOracle.createOracle(
+      WIF_feedId,
+      100,
+      -9
+    );
+    await confirmTransaction(provider.connection, txId);
+
+    try {
+      const roundPDA = await getRoundPDA(program, gamePDA);
+      await updateFeedAccount(
+        program,
+        game_authority,
+        gamePDA,
+        roundPDA,
+        wifFeedAddr
+      );
+      expect.fail("Feed account with different feed should be rejected.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("FeedIdMismatch");
+    }
+  });
+
+  it("should not allow updating the feed account during a round", async () => {
+    // start round
+    const [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    try {
+      await updateFeedAccount(
+        program,
+        game_authority,
+        gamePDA,
+        roundPDA,
+        priceFeedAddr
+      );
+      expect.fail("Feed account should not be updated during a round.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("RoundInProgress");
+    }
+  });
+
+  it("should not allow non-authority to update the feed account", async () => {
+    try {
+      const roundPDA = await getRoundPDA(program, gamePDA);
+      await updateFeedAccount(
+        program,
+        player,
+        gamePDA,
+        roundPDA,
+        priceFeedAddr
+      );
+      expect.fail("Player should not be able to update the feed account.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("ConstraintSeeds");
+    }
+  });
+});
@@ -0,0 +1,203 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+import { getAccount } from "@solana/spl-token";
+
+import { INTERVAL, SLOT_OFFSET, FEE } from "../config";
+import {
+  airdrop,
+  claimPrize,
+  closeBetting,
+  endRound,
+  getOracle,
+  getRoundPDA,
+  getToken,
+  getTokenAccount,
+  initializeGame,
+  initializeProtocol,
+  initializeRound,
+  placeBet,
+  setOraclePrice,
+  startRound,
+  updateFlatBand,
+  warpToSlot,
+} from "../helpers";
+import { pullOracleClient } from "../mock_oracle";
+
+describe("Update Flat Band", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let playerTokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let priceFeedAddr: PublicKey;
+  let pullOracle: pullOracleClient;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    playerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player
+    );
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+    pullOracle = oracle.pullOracle;
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      INTERVAL,
+      tokenAddress,
+      priceFeedAddr
+    );
+  });
+
+  async function playFlatRound(amount: number) {
+    const [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    const betPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      { bull: {} },
+      amount
+    );
+
+    await warpToSlot(provider, SLOT_OFFSET);
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+
+    // price moves by 5% (inside the 10% band)
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 105);
+    await endRound(
+      program,
+      game_authority,
+      gamePDA,
+      roundPDA,
+      gameVaultPDA,
+      roundVaultPDA,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    return [roundPDA, roundVaultPDA, betPDA];
+  }
+
+  it("should refund stakes of moves inside the band", async () => {
+    const pda = await getRoundPDA(program, gamePDA);
+    await updateFlatBand(program, game_authority, gamePDA, pda, 1000, {
+      refund: {},
+    });
+
+    const amount = 100 * 10 ** 9;
+    const [roundPDA, roundVaultPDA, betPDA] = await playFlatRound(amount);
+
+    // check result
+    const roundResult = (await program.account.round.fetch(roundPDA)).result;
+    expect(Object.keys(roundResult)[0].toString()).to.equal("noChange");
+
+    // claim refund
+    const initialBalance = (
+      await getAccount(provider.connection, playerTokenAccount.address)
+    ).amount;
+    await claimPrize(
+      program,
+      player,
+      gamePDA,
+      roundPDA,
+      betPDA,
+      tokenAddress,
+      roundVaultPDA,
+      playerTokenAccount
+    );
+    const finalBalance = (
+      await getAccount(provider.connection, playerTokenAccount.address)
+    ).amount;
+    expect(Number(finalBalance - initialBalance)).to.equal(amount);
+  });
+
+  it("should roll over the pools of moves inside the band", async () => {
+    const pda = await getRoundPDA(program, gamePDA);
+    await updateFlatBand(program, game_authority, gamePDA, pda, 1000, {
+      rollover: {},
+    });
+
+    const amount = 100 * 10 ** 9;
+    await playFlatRound(amount);
+
+    // check rollover
+    const rollover = (await program.account.game.fetch(gamePDA)).rollover;
+    expect(rollover.toNumber()).to.equal(amount);
+
+    // next round receives the rollover as bonus
+    const [nextRoundPDA, nextRoundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    const bonus = (await program.account.round.fetch(nextRoundPDA)).bonus;
+    expect(bonus.toNumber()).to.equal(amount);
+
+    const vaultBalance = (
+      await getAccount(provider.connection, nextRoundVaultPDA)
+    ).amount;
+    expect(Number(vaultBalance)).to.equal(amount);
+  });
+
+  it("should not allow a band of 100%", async () => {
+    try {
+      const pda = await getRoundPDA(program, gamePDA);
+      await updateFlatBand(program, game_authority, gamePDA, pda, 10000, {
+        houseTake: {},
+      });
+      expect.fail("Flat band should be below 100%.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("InvalidFlatBand");
+    }
+  });
+});
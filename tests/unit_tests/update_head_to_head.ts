@@ -0,0 +1,211 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+
+import { INTERVAL, SLOT_OFFSET, FEE, ETH_feedId } from "../config";
+import {
+  airdrop,
+  closeBetting,
+  endRound,
+  getOracle,
+  getRoundPDA,
+  getToken,
+  getTokenAccount,
+  initializeGame,
+  initializeProtocol,
+  initializeRound,
+  placeBet,
+  setOraclePrice,
+  startRound,
+  updateHeadToHead,
+  updateTwapWindow,
+  warpToSlot,
+} from "../helpers";
+import { pullOracleClient } from "../mock_oracle";
+
+describe("Update Head To Head", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let playerTokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let priceFeedAddr: PublicKey;
+  let priceFeedAddrB: PublicKey;
+  let pullOracle: pullOracleClient;
+  let pullOracleB: pullOracleClient;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    playerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player
+    );
+
+    // setup oracles
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+    pullOracle = oracle.pullOracle;
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 60);
+
+    const oracleB = await getOracle(provider, ETH_feedId);
+    priceFeedAddrB = oracleB.feed;
+    pullOracleB = oracleB.pullOracle;
+    await setOraclePrice(provider, pullOracleB, priceFeedAddrB, 60);
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      INTERVAL,
+      tokenAddress,
+      priceFeedAddr
+    );
+  });
+
+  it("should set the second feed", async () => {
+    const pda = await getRoundPDA(program, gamePDA);
+    await updateHeadToHead(
+      program,
+      game_authority,
+      gamePDA,
+      pda,
+      ETH_feedId,
+      priceFeedAddrB
+    );
+
+    const game = await program.account.game.fetch(gamePDA);
+    expect(game.feedAccountB.toBase58()).to.equal(priceFeedAddrB.toBase58());
+    expect(game.market).to.deep.equal({ headToHead: {} });
+  });
+
+  it("should not allow a price account of another feed", async () => {
+    const pda = await getRoundPDA(program, gamePDA);
+    try {
+      await updateHeadToHead(
+        program,
+        game_authority,
+        gamePDA,
+        pda,
+        ETH_feedId,
+        priceFeedAddr
+      );
+      expect.fail("Price account of another feed should be rejected.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("FeedIdMismatch");
+    }
+  });
+
+  it("should not allow TWAP settlement", async () => {
+    const pda = await getRoundPDA(program, gamePDA);
+    await updateHeadToHead(
+      program,
+      game_authority,
+      gamePDA,
+      pda,
+      ETH_feedId,
+      priceFeedAddrB
+    );
+
+    try {
+      await updateTwapWindow(program, game_authority, gamePDA, pda, 10);
+      expect.fail("TWAP window should be rejected.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("TwapNotSupported");
+    }
+  });
+
+  it("should settle on the relative performance of both feeds", async () => {
+    const pda = await getRoundPDA(program, gamePDA);
+    await updateHeadToHead(
+      program,
+      game_authority,
+      gamePDA,
+      pda,
+      ETH_feedId,
+      priceFeedAddrB
+    );
+
+    const [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(
+      program,
+      game_authority,
+      gamePDA,
+      roundPDA,
+      priceFeedAddr,
+      priceFeedAddrB
+    );
+
+    await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      { bull: {} },
+      100 * 10 ** 9
+    );
+
+    await warpToSlot(provider, SLOT_OFFSET);
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+
+    // both feeds fall, the first one less
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 54);
+    await setOraclePrice(provider, pullOracleB, priceFeedAddrB, 30);
+
+    await endRound(
+      program,
+      game_authority,
+      gamePDA,
+      roundPDA,
+      gameVaultPDA,
+      roundVaultPDA,
+      tokenAddress,
+      priceFeedAddr,
+      priceFeedAddrB
+    );
+
+    const round = await program.account.round.fetch(roundPDA);
+    expect(round.result).to.deep.equal({ bull: {} });
+    expect(round.startPriceB.toNumber()).to.be.greaterThan(0);
+  });
+});
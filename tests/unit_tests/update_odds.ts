@@ -0,0 +1,224 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+import { getAccount } from "@solana/spl-token";
+
+import { INTERVAL, SLOT_OFFSET, FEE } from "../config";
+import {
+  airdrop,
+  claimPrize,
+  closeBetting,
+  endRound,
+  getOracle,
+  getRoundPDA,
+  getToken,
+  getTokenAccount,
+  initializeGame,
+  initializeProtocol,
+  initializeRound,
+  placeBet,
+  setOraclePrice,
+  startRound,
+  updateOdds,
+  warpToSlot,
+} from "../helpers";
+import { pullOracleClient } from "../mock_oracle";
+
+describe("Update Odds", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let tokenAuthority: Keypair;
+  let playerTokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let priceFeedAddr: PublicKey;
+  let pullOracle: pullOracleClient;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+    tokenAuthority = token.authority;
+
+    playerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player
+    );
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+    pullOracle = oracle.pullOracle;
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 60);
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      INTERVAL,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    // switch to fixed odds of 1.9x with half of the house funds at risk
+    const pda = await getRoundPDA(program, gamePDA);
+    await updateOdds(
+      program,
+      game_authority,
+      gamePDA,
+      pda,
+      [19000, 19000],
+      5000
+    );
+  });
+
+  it("should not allow odds for missing outcomes", async () => {
+    const pda = await getRoundPDA(program, gamePDA);
+    try {
+      await updateOdds(
+        program,
+        game_authority,
+        gamePDA,
+        pda,
+        [19000, 19000, 30000],
+        5000
+      );
+      expect.fail("Odds should be rejected.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("InvalidOdds");
+    }
+  });
+
+  it("should not allow bets exceeding the exposure limit", async () => {
+    const [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    try {
+      await placeBet(
+        program,
+        gamePDA,
+        roundPDA,
+        roundVaultPDA,
+        tokenAddress,
+        player,
+        playerTokenAccount,
+        { bull: {} },
+        100 * 10 ** 9
+      );
+      expect.fail("Bet should exceed the exposure limit.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("ExposureLimitExceeded");
+    }
+  });
+
+  it("should pay winners at the quoted odds from the house", async () => {
+    // fund house
+    await splToken.mintTo(
+      provider.connection,
+      tokenAuthority,
+      tokenAddress,
+      gameVaultPDA,
+      tokenAuthority,
+      1000 * 10 ** 9
+    );
+
+    const [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    const amount = 100 * 10 ** 9;
+    const betPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      { bull: {} },
+      amount
+    );
+
+    // check quoted odds and exposure
+    const bet = await program.account.bet.fetch(betPDA);
+    expect(bet.oddsBps).to.equal(19000);
+    const game = await program.account.game.fetch(gamePDA);
+    expect(game.exposure.toNumber()).to.equal(90 * 10 ** 9);
+
+    await warpToSlot(provider, SLOT_OFFSET);
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 90);
+    await endRound(
+      program,
+      game_authority,
+      gamePDA,
+      roundPDA,
+      gameVaultPDA,
+      roundVaultPDA,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    // house paid the difference
+    const houseBalance = (await getAccount(provider.connection, gameVaultPDA))
+      .amount;
+    expect(Number(houseBalance)).to.equal(910 * 10 ** 9);
+
+    const initialBalance = (
+      await getAccount(provider.connection, playerTokenAccount.address)
+    ).amount;
+    await claimPrize(
+      program,
+      player,
+      gamePDA,
+      roundPDA,
+      betPDA,
+      tokenAddress,
+      roundVaultPDA,
+      playerTokenAccount
+    );
+    const finalBalance = (
+      await getAccount(provider.connection, playerTokenAccount.address)
+    ).amount;
+    expect(Number(finalBalance - initialBalance)).to.equal(190 * 10 ** 9);
+  });
+});
@@ -0,0 +1,124 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+
+import { INTERVAL, FEE } from "../config";
+import {
+  airdrop,
+  getOracle,
+  getRoundPDA,
+  getToken,
+  initializeGame,
+  initializeProtocol,
+  initializeRound,
+  startRound,
+  updatePriceSource,
+} from "../helpers";
+import { pullOracleClient } from "../mock_oracle";
+
+describe("Update Price Source", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let priceFeedAddr: PublicKey;
+  let pullOracle: pullOracleClient;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+
+    // create token
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+    pullOracle = oracle.pullOracle;
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      INTERVAL,
+      tokenAddress,
+      priceFeedAddr
+    );
+  });
+
+  it("should start rounds with the ema price", async () => {
+    const pda = await getRoundPDA(program, gamePDA);
+    await updatePriceSource(program, game_authority, gamePDA, pda, { ema: {} }, 0);
+
+    // check price source
+    const priceSource = (await program.account.game.fetch(gamePDA))
+      .priceSource;
+    expect(Object.keys(priceSource)[0].toString()).to.equal("ema");
+
+    // start round
+    const [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    // mock oracle sets ema to the spot price
+    const startPrice = (await program.account.round.fetch(roundPDA))
+      .startPrice;
+    expect(startPrice.toNumber()).to.equal(100 * 10 ** 9);
+  });
+
+  it("should not allow an ema guard without deviation", async () => {
+    try {
+      const pda = await getRoundPDA(program, gamePDA);
+      await updatePriceSource(
+        program,
+        game_authority,
+        gamePDA,
+        pda,
+        { spotWithEmaGuard: {} },
+        0
+      );
+      expect.fail("EMA guard needs a deviation.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("InvalidEmaDeviation");
+    }
+  });
+
+  it("should not allow non-authority to update the price source", async () => {
+    try {
+      const pda = await getRoundPDA(program, gamePDA);
+      await updatePriceSource(program, player, gamePDA, pda, { ema: {} }, 0);
+      expect.fail("Player should not be able to update the price source.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("ConstraintSeeds");
+    }
+  });
+});
@@ -0,0 +1,232 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BullBearProgram } from "../../target/types/bull_bear_program";
+import { expect } from "chai";
+import * as splToken from "@solana/spl-token";
+import { getAccount } from "@solana/spl-token";
+
+import { INTERVAL, SLOT_OFFSET, FEE } from "../config";
+import {
+  airdrop,
+  cancelBet,
+  claimPrize,
+  closeBetting,
+  endRound,
+  getOracle,
+  getRoundPDA,
+  getToken,
+  getTokenAccount,
+  initializeGame,
+  initializeProtocol,
+  initializeRound,
+  placeBet,
+  setOraclePrice,
+  startRound,
+  updateTokenizedBets,
+  warpToSlot,
+} from "../helpers";
+import { pullOracleClient } from "../mock_oracle";
+
+describe("Update Tokenized Bets", () => {
+  // provider
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // program
+  const program = anchor.workspace.BullBearProgram as Program<BullBearProgram>;
+
+  let authority: Keypair;
+  let game_authority: Keypair;
+  let player: Keypair;
+  let buyer: Keypair;
+  let protocolPDA: PublicKey;
+  let tokenAddress: PublicKey;
+  let playerTokenAccount: splToken.Account;
+  let buyerTokenAccount: splToken.Account;
+  let gamePDA: PublicKey;
+  let gameVaultPDA: PublicKey;
+  let roundPDA: PublicKey;
+  let roundVaultPDA: PublicKey;
+  let betPDA: PublicKey;
+  let receiptMint: PublicKey;
+  let priceFeedAddr: PublicKey;
+  let pullOracle: pullOracleClient;
+  const amount = 100 * 10 ** 9;
+  beforeEach("Setup", async () => {
+    // Generate keypairs
+    authority = anchor.web3.Keypair.generate();
+    game_authority = anchor.web3.Keypair.generate();
+    player = anchor.web3.Keypair.generate();
+    buyer = anchor.web3.Keypair.generate();
+
+    // Fund accounts
+    await airdrop(provider.connection, authority.publicKey);
+    await airdrop(provider.connection, game_authority.publicKey);
+    await airdrop(provider.connection, player.publicKey);
+    await airdrop(provider.connection, buyer.publicKey);
+
+    // create token accounts
+    const token = await getToken(provider);
+    tokenAddress = token.address;
+
+    playerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      player
+    );
+
+    buyerTokenAccount = await getTokenAccount(
+      provider.connection,
+      tokenAddress,
+      token.authority,
+      buyer
+    );
+
+    // setup oracle
+    const oracle = await getOracle(provider);
+    priceFeedAddr = oracle.feed;
+    pullOracle = oracle.pullOracle;
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 60);
+
+    // Initialize Protocol
+    protocolPDA = await initializeProtocol(program, authority, FEE);
+
+    // Initialize Game
+    [gamePDA, gameVaultPDA] = await initializeGame(
+      program,
+      game_authority,
+      protocolPDA,
+      INTERVAL,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    const pda = await getRoundPDA(program, gamePDA);
+    await updateTokenizedBets(program, game_authority, gamePDA, pda, true);
+
+    [roundPDA, roundVaultPDA] = await initializeRound(
+      program,
+      game_authority,
+      gamePDA,
+      tokenAddress
+    );
+    await startRound(program, game_authority, gamePDA, roundPDA, priceFeedAddr);
+
+    betPDA = await placeBet(
+      program,
+      gamePDA,
+      roundPDA,
+      roundVaultPDA,
+      tokenAddress,
+      player,
+      playerTokenAccount,
+      { bull: {} },
+      amount
+    );
+    receiptMint = (await program.account.bet.fetch(betPDA)).receiptMint;
+  });
+
+  it("should mint a receipt for the bet", async () => {
+    const receiptAccount = splToken.getAssociatedTokenAddressSync(
+      receiptMint,
+      player.publicKey
+    );
+    const balance = (await getAccount(provider.connection, receiptAccount))
+      .amount;
+    expect(Number(balance)).to.equal(1);
+  });
+
+  it("should not allow cancelling tokenized bets", async () => {
+    try {
+      await cancelBet(
+        program,
+        player,
+        gamePDA,
+        roundPDA,
+        betPDA,
+        tokenAddress,
+        roundVaultPDA,
+        playerTokenAccount
+      );
+      expect.fail("Tokenized bet should not be cancelled.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("BetIsTokenized");
+    }
+  });
+
+  it("should pay the holder of the receipt", async () => {
+    // sell receipt
+    const playerReceipt = splToken.getAssociatedTokenAddressSync(
+      receiptMint,
+      player.publicKey
+    );
+    const buyerReceipt = await splToken.getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      buyer,
+      receiptMint,
+      buyer.publicKey
+    );
+    await splToken.transfer(
+      provider.connection,
+      player,
+      playerReceipt,
+      buyerReceipt.address,
+      player,
+      1
+    );
+
+    // end round
+    await warpToSlot(provider, SLOT_OFFSET);
+    await closeBetting(program, game_authority, gamePDA, roundPDA);
+    await setOraclePrice(provider, pullOracle, priceFeedAddr, 90);
+    await endRound(
+      program,
+      game_authority,
+      gamePDA,
+      roundPDA,
+      gameVaultPDA,
+      roundVaultPDA,
+      tokenAddress,
+      priceFeedAddr
+    );
+
+    // original player can no longer claim
+    try {
+      await claimPrize(
+        program,
+        player,
+        gamePDA,
+        roundPDA,
+        betPDA,
+        tokenAddress,
+        roundVaultPDA,
+        playerTokenAccount
+      );
+      expect.fail("Seller should not claim the prize.");
+    } catch (_err) {
+      const err = anchor.AnchorError.parse(_err.logs);
+      expect(err.error.errorCode.code).to.equal("ReceiptNotHeld");
+    }
+
+    const initialBalance = (
+      await getAccount(provider.connection, buyerTokenAccount.address)
+    ).amount;
+    await claimPrize(
+      program,
+      buyer,
+      gamePDA,
+      roundPDA,
+      betPDA,
+      tokenAddress,
+      roundVaultPDA,
+      buyerTokenAccount
+    );
+    const finalBalance = (
+      await getAccount(provider.connection, buyerTokenAccount.address)
+    ).amount;
+    expect(Number(finalBalance - initialBalance)).to.equal(amount);
+  });
+});